@@ -0,0 +1,271 @@
+use crate::{
+    input,
+    solution::{DayReport, Part},
+};
+use anyhow::{Context, Error, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    fmt::{Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable naming the directory of the answers registry.
+pub const ANSWERS_VAR: &str = "AOC_ANSWERS_DIR";
+
+/// The directory of the answers registry when `AOC_ANSWERS_DIR` isn't set.
+pub const DEFAULT_DIR: &str = "answers";
+
+/// The verified answers of one input.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Answers {
+    pub day: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn new(day: u8) -> Self {
+        Answers {
+            day,
+            ..Default::default()
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: impl Into<String>) {
+        let answer = Some(answer.into());
+        match part {
+            Part::One => self.part_one = answer,
+            Part::Two => self.part_two = answer,
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unverified,
+        }
+    }
+}
+
+/// How an answer compares to the registry.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    Unverified,
+    /// Unverified until now, stored as the answer of its input from here on.
+    Recorded,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong { expected } => write!(f, "wrong, expected {expected}"),
+            Verdict::Unverified => write!(f, "unverified"),
+            Verdict::Recorded => write!(f, "recorded"),
+        }
+    }
+}
+
+/// A directory of verified answers with one `<input hash>.json` file per input, so the inputs
+/// of several people can be checked side by side.
+///
+/// The answers of the inputs embedded with the `embedded-input` feature live in
+/// `embedded/day<N>.json`, so they are known before anyone has hashed those inputs.
+#[derive(Clone, Debug)]
+pub struct Registry {
+    dir: PathBuf,
+    record: bool,
+}
+
+impl Registry {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Registry {
+            dir: dir.into(),
+            record: false,
+        }
+    }
+
+    /// The registry in the directory named by `AOC_ANSWERS_DIR`, or `answers`.
+    pub fn from_env() -> Self {
+        Registry::new(env::var_os(ANSWERS_VAR).unwrap_or_else(|| DEFAULT_DIR.into()))
+    }
+
+    /// Whether [`Registry::verify`] records the answers of inputs without verified ones as
+    /// correct.
+    pub fn recording(self, record: bool) -> Self {
+        Registry { record, ..self }
+    }
+
+    fn path(&self, hash: &str) -> PathBuf {
+        self.dir.join(format!("{hash}.json"))
+    }
+
+    fn embedded_path(&self, day: u8) -> PathBuf {
+        self.dir.join("embedded").join(format!("day{day}.json"))
+    }
+
+    /// The answers known for the input with the given hash, empty if there are none yet.
+    pub fn load(&self, day: u8, hash: &str) -> Result<Answers> {
+        Ok(read(&self.path(hash), day)?.unwrap_or_else(|| Answers::new(day)))
+    }
+
+    /// Like [`Registry::load`], falling back to the answers of the day's embedded input when
+    /// the input is that one.
+    pub fn load_or_embedded(&self, day: u8, hash: &str, embedded: Option<&str>) -> Result<Answers> {
+        if let Some(answers) = read(&self.path(hash), day)? {
+            return Ok(answers);
+        }
+        match embedded {
+            Some(data) if input::hash(data) == hash => {
+                Ok(read(&self.embedded_path(day), day)?.unwrap_or_else(|| Answers::new(day)))
+            }
+            _ => Ok(Answers::new(day)),
+        }
+    }
+
+    pub fn save(&self, hash: &str, answers: &Answers) -> Result<()> {
+        let path = self.path(hash);
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| {
+                let data = serde_json::to_string_pretty(answers)?;
+                fs::write(&path, data + "\n")
+            })
+            .with_context(|| format!("Failed to write answers to {}", path.display()))
+    }
+
+    /// Checks the answers of a day against the registry, one verdict per part, recording the
+    /// unknown ones when asked to.
+    pub fn verify(
+        &self,
+        report: &DayReport,
+        hash: &str,
+        embedded: Option<&str>,
+    ) -> Result<Vec<Verdict>> {
+        let mut answers = self.load_or_embedded(report.day, hash, embedded)?;
+        let mut recorded = false;
+
+        let verdicts = report
+            .parts
+            .iter()
+            .map(|part| match &part.answer {
+                Ok(answer) => match answers.check(part.part, answer) {
+                    Verdict::Unverified if self.record => {
+                        answers.set(part.part, answer);
+                        recorded = true;
+                        Verdict::Recorded
+                    }
+                    verdict => verdict,
+                },
+                Err(_) => Verdict::Unverified,
+            })
+            .collect();
+
+        if recorded {
+            self.save(hash, &answers)?;
+        }
+
+        Ok(verdicts)
+    }
+}
+
+/// The answers in a file of the registry, `None` if there is no such file.
+fn read(path: &Path, day: u8) -> Result<Option<Answers>> {
+    let answers = match fs::read_to_string(path) {
+        Ok(data) => serde_json::from_str::<Answers>(&data)
+            .with_context(|| format!("Failed to read answers from {}", path.display()))?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(Error::new(e))
+                .with_context(|| format!("Failed to read answers from {}", path.display()));
+        }
+    };
+
+    if answers.day != day {
+        return Err(Error::msg(format!(
+            "{} holds the answers of day {}, not day {day}",
+            path.display(),
+            answers.day
+        )));
+    }
+
+    Ok(Some(answers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::new(1);
+        answers.set(Part::One, "1681");
+
+        assert_eq!(answers.check(Part::One, "1681"), Verdict::Correct);
+        assert_eq!(
+            answers.check(Part::One, "1682"),
+            Verdict::Wrong {
+                expected: "1681".to_string()
+            }
+        );
+        assert_eq!(answers.check(Part::Two, "1704"), Verdict::Unverified);
+    }
+
+    #[test]
+    fn test_registry() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        let registry = Registry::new(&dir);
+
+        assert_eq!(registry.load(16, "abc")?, Answers::new(16));
+
+        let mut answers = Answers::new(16);
+        answers.set(Part::One, "913");
+        registry.save("abc", &answers)?;
+
+        assert_eq!(registry.load(16, "abc")?, answers);
+        assert!(registry.load(17, "abc").is_err());
+
+        // the embedded answers only count for the embedded input
+        let mut embedded = Answers::new(16);
+        embedded.set(Part::Two, "1510977819698");
+        fs::create_dir_all(dir.join("embedded"))?;
+        fs::write(
+            dir.join("embedded/day16.json"),
+            serde_json::to_string(&embedded)?,
+        )?;
+        let hash = input::hash("D2FE28");
+        assert_eq!(
+            registry.load_or_embedded(16, &hash, Some("D2FE28"))?,
+            embedded
+        );
+        assert_eq!(
+            registry.load_or_embedded(16, &hash, Some("38006F45291200"))?,
+            Answers::new(16)
+        );
+        assert_eq!(
+            registry.load_or_embedded(16, "abc", Some("D2FE28"))?,
+            answers
+        );
+
+        fs::remove_dir_all(dir)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use crate::input::Source;
+use anyhow::{Context, Error, Result};
+use std::{env, str::FromStr};
+
+/// The command line of a day binary run with a flag of its own instead of solving the puzzle,
+/// such as `day2 --trace aimed input.txt`, taken apart into options, arguments and the input path.
+///
+/// Options are found wherever they are given, so they should be taken before the arguments, and
+/// the one argument left at the end names the input.
+#[derive(Debug)]
+pub struct Command {
+    usage: &'static str,
+    args: Vec<String>,
+}
+
+impl Command {
+    /// The arguments of the binary after `flag`, when it is the first one.
+    pub fn from_env(flag: &str, usage: &'static str) -> Option<Self> {
+        Command::new(env::args().skip(1), flag, usage)
+    }
+
+    pub fn new(
+        args: impl IntoIterator<Item = String>,
+        flag: &str,
+        usage: &'static str,
+    ) -> Option<Self> {
+        let mut args = args.into_iter();
+        (args.next()? == flag).then(|| Command {
+            usage,
+            args: args.collect(),
+        })
+    }
+
+    /// The error showing how the command is used.
+    pub fn usage(&self) -> Error {
+        Error::msg(self.usage)
+    }
+
+    /// Whether the switch `name` is given.
+    pub fn switch(&mut self, name: &str) -> bool {
+        match self.args.iter().position(|arg| arg == name) {
+            Some(index) => {
+                self.args.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The value of the option `name`, if given.
+    pub fn option<T>(&mut self, name: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Into<Error>,
+    {
+        let Some(index) = self.args.iter().position(|arg| arg == name) else {
+            return Ok(None);
+        };
+        let Some(value) = self.args.get(index + 1).cloned() else {
+            return Err(self.usage());
+        };
+        self.args.drain(index..=index + 1);
+
+        parse(name, &value).map(Some)
+    }
+
+    /// The next argument, described as `name` when it is invalid.
+    pub fn argument<T>(&mut self, name: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Into<Error>,
+    {
+        let Some(index) = self.args.iter().position(|arg| !is_option(arg)) else {
+            return Err(self.usage());
+        };
+        let value = self.args.remove(index);
+
+        parse(name, &value)
+    }
+
+    /// The input named by the argument left, stdin when there is none or it is `-`, failing when
+    /// anything else is left.
+    pub fn source(self) -> Result<Source> {
+        match self.args.as_slice() {
+            [] => Ok(Source::from_arg(None)),
+            [path] if !is_option(path) => Ok(Source::from_arg(Some(path))),
+            _ => Err(self.usage()),
+        }
+    }
+}
+
+fn is_option(arg: &str) -> bool {
+    arg.starts_with("--")
+}
+
+fn parse<T>(name: &str, value: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    value
+        .parse::<T>()
+        .map_err(Into::into)
+        .with_context(|| format!("Invalid {name} {value:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USAGE: &str = "Usage: dayN --flag <COUNT> [--scale N] [--wrap] [PATH]";
+
+    fn command(args: &str) -> Option<Command> {
+        Command::new(args.split_whitespace().map(String::from), "--flag", USAGE)
+    }
+
+    #[test]
+    fn test_command() -> Result<()> {
+        assert!(command("").is_none());
+        assert!(command("input.txt").is_none());
+
+        let mut cmd = command("--flag 3 --wrap input.txt --scale 2").context("flag")?;
+        assert_eq!(cmd.option::<u32>("--scale")?, Some(2));
+        assert!(cmd.switch("--wrap"));
+        assert!(!cmd.switch("--wrap"));
+        assert_eq!(cmd.argument::<u32>("count")?, 3);
+        assert!(matches!(cmd.source()?, Source::Path(path) if path.as_os_str() == "input.txt"));
+
+        let mut cmd = command("--flag 3 -").context("flag")?;
+        assert_eq!(cmd.option::<u32>("--scale")?, None);
+        assert_eq!(cmd.argument::<u32>("count")?, 3);
+        assert!(matches!(cmd.source()?, Source::Stdin));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_command_errors() -> Result<()> {
+        let mut cmd = command("--flag").context("flag")?;
+        assert_eq!(cmd.argument::<u32>("count").unwrap_err().to_string(), USAGE);
+
+        let mut cmd = command("--flag x").context("flag")?;
+        let error = cmd.argument::<u32>("count").unwrap_err();
+        assert_eq!(error.to_string(), "Invalid count \"x\"");
+
+        let mut cmd = command("--flag 3 --scale").context("flag")?;
+        assert_eq!(cmd.option::<u32>("--scale").unwrap_err().to_string(), USAGE);
+
+        let cmd = command("--flag --unknown").context("flag")?;
+        assert_eq!(cmd.source().unwrap_err().to_string(), USAGE);
+        let cmd = command("--flag a.txt b.txt").context("flag")?;
+        assert_eq!(cmd.source().unwrap_err().to_string(), USAGE);
+
+        Ok(())
+    }
+}
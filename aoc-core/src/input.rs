@@ -0,0 +1,94 @@
+use anyhow::{Context, Error, Result};
+use sha2::{Digest, Sha256};
+use std::{
+    borrow::Cow,
+    env, fs,
+    io::{self, BufRead, BufReader, IsTerminal, Read},
+    path::PathBuf,
+};
+
+/// Environment variable naming the input file when no path argument is given.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+    Embedded(&'static str),
+}
+
+impl Source {
+    /// The source named by a path argument of a day binary, stdin when left out or `-`.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None | Some("-") => Source::Stdin,
+            Some(path) => Source::Path(path.into()),
+        }
+    }
+
+    /// Resolves the input source in order of precedence: the first command line
+    /// argument (`-` meaning stdin), the `AOC_INPUT` environment variable, the
+    /// embedded input and finally a piped stdin.
+    pub fn resolve(embedded: Option<&'static str>) -> Result<Self> {
+        if let Some(arg) = env::args_os().nth(1) {
+            if arg == "-" {
+                return Ok(Source::Stdin);
+            }
+            return Ok(Source::Path(arg.into()));
+        }
+
+        if let Some(path) = env::var_os(INPUT_VAR) {
+            return Ok(Source::Path(path.into()));
+        }
+
+        if let Some(data) = embedded {
+            return Ok(Source::Embedded(data));
+        }
+
+        if !io::stdin().is_terminal() {
+            return Ok(Source::Stdin);
+        }
+
+        Err(Error::msg(format!(
+            "No input given: pass a path (or - for stdin), set {INPUT_VAR} or build with the embedded-input feature"
+        )))
+    }
+
+    pub fn read(&self) -> Result<Cow<'static, str>> {
+        match self {
+            Source::Path(path) => fs::read_to_string(path)
+                .map(Cow::Owned)
+                .with_context(|| format!("Failed to read input from {}", path.display())),
+            Source::Stdin => {
+                let mut buf = String::new();
+                io::stdin()
+                    .read_to_string(&mut buf)
+                    .context("Failed to read input from stdin")?;
+                Ok(Cow::Owned(buf))
+            }
+            Source::Embedded(data) => Ok(Cow::Borrowed(data)),
+        }
+    }
+
+    /// Opens the input to be read line by line instead of all at once.
+    pub fn reader(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            Source::Path(path) => fs::File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .with_context(|| format!("Failed to read input from {}", path.display())),
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::Embedded(data) => Ok(Box::new(data.as_bytes())),
+        }
+    }
+}
+
+/// The SHA-256 of an input in hex, identifying it without publishing its content.
+pub fn hash(data: &str) -> String {
+    format!("{:x}", Sha256::digest(data.as_bytes()))
+}
+
+/// Reads the puzzle input from the first available [`Source`].
+pub fn load(embedded: Option<&'static str>) -> Result<Cow<'static, str>> {
+    Source::resolve(embedded)?.read()
+}
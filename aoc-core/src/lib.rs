@@ -0,0 +1,10 @@
+pub mod answers;
+pub mod args;
+pub mod input;
+pub mod parse;
+pub mod record;
+pub mod solution;
+
+pub use parse::ParseError;
+pub use solution::run;
+pub use solution::{Part, Solution};
@@ -0,0 +1,262 @@
+use nom::{
+    error::{ErrorKind, FromExternalError},
+    AsBytes, AsChar, Compare, Input,
+};
+use std::fmt::{Display, Formatter};
+
+/// The result of the nom parsers of every day, using [`Error`] to remember what was expected.
+pub type IResult<I, O> = nom::IResult<I, O, Error<I>>;
+
+/// Longest part of a line shown on either side of the failing column.
+const SNIPPET_RADIUS: usize = 30;
+
+/// Something a parser expected to find but didn't.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expected {
+    Char(char),
+    Tag(&'static str),
+    OneOf(&'static str),
+    Kind(ErrorKind),
+    External(String),
+    /// Anything else, described in words.
+    Other(String),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Char(c) => write!(f, "{c:?}"),
+            Expected::Tag(tag) => write!(f, "{tag:?}"),
+            Expected::OneOf(chars) => write!(f, "one of {chars:?}"),
+            Expected::Kind(kind) => match kind {
+                ErrorKind::Digit => write!(f, "a number"),
+                ErrorKind::Alpha => write!(f, "letters"),
+                ErrorKind::CrLf => write!(f, "a line ending"),
+                ErrorKind::Eof => write!(f, "end of input"),
+                kind => write!(f, "{}", kind.description().to_lowercase()),
+            },
+            Expected::External(e) => write!(f, "a valid value ({e})"),
+            Expected::Other(description) => write!(f, "{description}"),
+        }
+    }
+}
+
+/// The nom error type of every day: the position of the failure and what would have matched there.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error<I> {
+    pub input: I,
+    pub expected: Vec<Expected>,
+}
+
+impl<I> Error<I> {
+    pub fn new(input: I, expected: Expected) -> Self {
+        Error {
+            input,
+            expected: vec![expected],
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self.expected.as_slice() {
+            [expected] => expected.to_string(),
+            expected => {
+                let expected = expected
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!("any of {expected}")
+            }
+        }
+    }
+}
+
+impl<I: Input> nom::error::ParseError<I> for Error<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Error::new(input, Expected::Kind(kind))
+    }
+
+    fn append(_: I, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        Error::new(input, Expected::Char(c))
+    }
+
+    fn or(mut self, other: Self) -> Self {
+        // keep the branch that got furthest, or merge the alternatives that failed at the same spot
+        match self.input.input_len().cmp(&other.input.input_len()) {
+            std::cmp::Ordering::Less => self,
+            std::cmp::Ordering::Greater => other,
+            std::cmp::Ordering::Equal => {
+                for expected in other.expected {
+                    if !self.expected.contains(&expected) {
+                        self.expected.push(expected);
+                    }
+                }
+                self
+            }
+        }
+    }
+}
+
+impl<I, E: Display> FromExternalError<I, E> for Error<I> {
+    fn from_external_error(input: I, _: ErrorKind, e: E) -> Self {
+        Error::new(input, Expected::External(e.to_string()))
+    }
+}
+
+/// Like [`nom::bytes::complete::tag`], but remembers the tag that was expected.
+pub fn tag<I>(tag: &'static str) -> impl Fn(I) -> IResult<I, I>
+where
+    I: Input + Compare<&'static str>,
+{
+    move |input: I| {
+        nom::bytes::complete::tag(tag)(input)
+            .map_err(|e| e.map(|e: Error<I>| Error::new(e.input, Expected::Tag(tag))))
+    }
+}
+
+/// Like [`nom::character::complete::one_of`], but remembers the characters that were expected.
+pub fn one_of<I>(chars: &'static str) -> impl FnMut(I) -> IResult<I, char>
+where
+    I: Input,
+    <I as Input>::Item: AsChar,
+{
+    let mut parser = nom::character::complete::one_of(chars);
+    move |input: I| {
+        parser(input).map_err(|e| e.map(|e: Error<I>| Error::new(e.input, Expected::OneOf(chars))))
+    }
+}
+
+/// A parse failure of a day's input, located by line and column.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub snippet: String,
+    caret: usize,
+}
+
+impl ParseError {
+    /// Locates `offset`, a byte offset into `original`, and describes what was expected there.
+    pub fn at(day: u8, original: &[u8], offset: usize, expected: impl Display) -> Self {
+        let offset = offset.min(original.len());
+        let line_start = original[..offset]
+            .iter()
+            .rposition(|b| *b == b'\n')
+            .map_or(0, |pos| pos + 1);
+        let line_end = original[offset..]
+            .iter()
+            .position(|b| *b == b'\n')
+            .map_or(original.len(), |pos| offset + pos);
+
+        let before = String::from_utf8_lossy(&original[line_start..offset]);
+        let after = String::from_utf8_lossy(&original[offset..line_end]);
+        let after = after.trim_end_matches('\r');
+        let line = original[..line_start]
+            .iter()
+            .filter(|b| **b == b'\n')
+            .count()
+            + 1;
+        let column = before.chars().count() + 1;
+
+        let skip = before.chars().count().saturating_sub(SNIPPET_RADIUS);
+        let before = before.chars().skip(skip).collect::<String>();
+        let caret = before.chars().count();
+        let snippet = before + &after.chars().take(SNIPPET_RADIUS).collect::<String>();
+
+        ParseError {
+            day,
+            line,
+            column,
+            expected: expected.to_string(),
+            snippet,
+            caret,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Day {} failed to parse at line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )?;
+        writeln!(f, "  {}", self.snippet)?;
+        write!(f, "  {}^", " ".repeat(self.caret))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Turns the outcome of a day's top level parser into its output, failing on anything but
+/// trailing whitespace left unconsumed.
+pub fn finish<I, O>(day: u8, original: I, result: IResult<I, O>) -> Result<O, ParseError>
+where
+    I: Input + AsBytes,
+{
+    let bytes = original.as_bytes();
+    let offset = |rest: &I| bytes.len() - rest.input_len();
+
+    match result {
+        Ok((rest, output)) => {
+            let trailing = rest.as_bytes();
+            match trailing.iter().position(|b| !b.is_ascii_whitespace()) {
+                None => Ok(output),
+                Some(pos) => Err(ParseError::at(
+                    day,
+                    bytes,
+                    offset(&rest) + pos,
+                    Expected::Kind(ErrorKind::Eof),
+                )),
+            }
+        }
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            Err(ParseError::at(day, bytes, offset(&e.input), e.describe()))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(day, bytes, bytes.len(), "more input")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{character::complete, multi::separated_list1, Parser};
+
+    fn parse(input: &str) -> IResult<&str, Vec<u16>> {
+        separated_list1(tag(", "), complete::u16).parse(input)
+    }
+
+    #[test]
+    fn test_finish() {
+        assert_eq!(
+            finish(1, "1, 2, 3\n", parse("1, 2, 3\n")),
+            Ok(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_finish_error() {
+        let input = "x, 2";
+        let error = finish(1, input, parse(input)).unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.expected, "a number");
+        assert_eq!(error.snippet, "x, 2");
+    }
+
+    #[test]
+    fn test_finish_trailing_input() {
+        let input = "1, 2\n3; 4";
+        let error = finish(1, input, parse(input)).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "end of input");
+        assert_eq!(error.to_string().lines().last(), Some("  ^"));
+    }
+}
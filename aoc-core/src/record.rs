@@ -0,0 +1,206 @@
+use crate::{
+    answers::Verdict,
+    solution::{DayReport, Part},
+};
+use anyhow::{Error, Result};
+use serde::Serialize;
+use std::{io::Write, str::FromStr, time::Duration};
+use took::Took;
+
+/// Environment variable selecting a machine-readable output format for the day binaries.
+pub const FORMAT_VAR: &str = "AOC_FORMAT";
+
+/// Machine-readable output formats, one record per day and part.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// One JSON object per line.
+    Json,
+    /// Comma separated values with a header row.
+    Csv,
+}
+
+impl Format {
+    /// The format set through `AOC_FORMAT`, if any.
+    pub fn from_env() -> Result<Option<Self>> {
+        std::env::var(FORMAT_VAR)
+            .ok()
+            .filter(|format| !format.is_empty())
+            .map(|format| format.parse())
+            .transpose()
+    }
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "json" | "jsonl" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(Error::msg(format!(
+                "Unknown output format {s:?}, expected json or csv"
+            ))),
+        }
+    }
+}
+
+/// The answer of one part of a day together with how long it took.
+///
+/// Times are in nanoseconds; the parse time is shared by both parts of a day.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub input_hash: Option<String>,
+    /// Whether the answer matches the registry, unknown for unverified answers.
+    pub correct: Option<bool>,
+    pub expected: Option<String>,
+}
+
+impl Record {
+    /// One record per solved part of `report`.
+    pub fn of(report: &DayReport, input_hash: &str) -> Vec<Record> {
+        report
+            .parts
+            .iter()
+            .map(|part| {
+                let (answer, error) = match &part.answer {
+                    Ok(answer) => (Some(answer.clone()), None),
+                    Err(e) => (None, Some(format!("{e:#}"))),
+                };
+                Record {
+                    day: report.day,
+                    part: part.part.number(),
+                    answer,
+                    error,
+                    parse_ns: Some(nanos(&report.parse)),
+                    solve_ns: Some(nanos(&part.took)),
+                    input_hash: Some(input_hash.to_string()),
+                    correct: None,
+                    expected: None,
+                }
+            })
+            .collect()
+    }
+
+    /// One record per requested part of a day that could not be read or parsed.
+    pub fn failed(day: u8, parts: &[Part], error: &Error, input_hash: Option<&str>) -> Vec<Record> {
+        parts
+            .iter()
+            .map(|part| Record {
+                day,
+                part: part.number(),
+                answer: None,
+                error: Some(format!("{error:#}")),
+                parse_ns: None,
+                solve_ns: None,
+                input_hash: input_hash.map(str::to_string),
+                correct: None,
+                expected: None,
+            })
+            .collect()
+    }
+
+    pub fn verify(&mut self, verdict: &Verdict) {
+        (self.correct, self.expected) = match verdict {
+            Verdict::Correct => (Some(true), self.answer.clone()),
+            Verdict::Wrong { expected } => (Some(false), Some(expected.clone())),
+            Verdict::Unverified | Verdict::Recorded => (None, None),
+        };
+    }
+}
+
+fn nanos(took: &Took) -> u64 {
+    let duration: &Duration = took.as_std();
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Writes `records` to `out` in the given format.
+pub fn write(format: Format, records: &[Record], mut out: impl Write) -> Result<()> {
+    match format {
+        Format::Json => {
+            for record in records {
+                serde_json::to_writer(&mut out, record)?;
+                writeln!(out)?;
+            }
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            for record in records {
+                writer.serialize(record)?;
+            }
+            writer.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 1,
+                part: 1,
+                answer: Some("7".to_string()),
+                error: None,
+                parse_ns: Some(1200),
+                solve_ns: Some(300),
+                input_hash: Some("ab12".to_string()),
+                correct: Some(true),
+                expected: Some("7".to_string()),
+            },
+            Record {
+                day: 1,
+                part: 2,
+                answer: None,
+                error: Some("No result found".to_string()),
+                parse_ns: Some(1200),
+                solve_ns: Some(50),
+                input_hash: Some("ab12".to_string()),
+                correct: None,
+                expected: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_write_json() -> Result<()> {
+        let mut out = Vec::new();
+        write(Format::Json, &records(), &mut out)?;
+
+        assert_eq!(
+            String::from_utf8(out)?,
+            concat!(
+                r#"{"day":1,"part":1,"answer":"7","error":null,"parse_ns":1200,"solve_ns":300,"input_hash":"ab12","correct":true,"expected":"7"}"#,
+                "\n",
+                r#"{"day":1,"part":2,"answer":null,"error":"No result found","parse_ns":1200,"solve_ns":50,"input_hash":"ab12","correct":null,"expected":null}"#,
+                "\n",
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_csv() -> Result<()> {
+        let mut out = Vec::new();
+        write(Format::Csv, &records(), &mut out)?;
+
+        assert_eq!(
+            String::from_utf8(out)?,
+            "day,part,answer,error,parse_ns,solve_ns,input_hash,correct,expected\n\
+             1,1,7,,1200,300,ab12,true,7\n\
+             1,2,,No result found,1200,50,ab12,,\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{
+    answers::{Registry, Verdict},
+    record::{self, Format, Record},
+    ParseError,
+};
+use anyhow::{Error, Result};
+use std::fmt::{Display, Formatter};
+use took::Took;
+
+/// A single day of the event, solved through a parser and two puzzle parts.
+pub trait Solution {
+    const DAY: u8;
+
+    /// The puzzle input baked in with the `embedded-input` feature, if any.
+    const EMBEDDED: Option<&'static str> = None;
+
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1>;
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2>;
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "one"),
+            Part::Two => write!(f, "two"),
+        }
+    }
+}
+
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<String>,
+    pub took: Took,
+}
+
+pub struct DayReport {
+    pub day: u8,
+    pub parse: Took,
+    pub parts: Vec<PartReport>,
+}
+
+/// Type-erased entry point of a [`Solution`], so days can be dispatched at runtime.
+pub type Solver = fn(&str, &[Part]) -> Result<DayReport>;
+
+/// A day as seen by the runner.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub day: u8,
+    pub solve: Solver,
+    pub embedded: Option<&'static str>,
+}
+
+impl Entry {
+    pub fn of<S: Solution>() -> Self {
+        Entry {
+            day: S::DAY,
+            solve: solve::<S>,
+            embedded: S::EMBEDDED,
+        }
+    }
+}
+
+/// Parses the input and solves the requested parts, timing every step.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<DayReport> {
+    let (parse, result) = took::took(|| S::parse(input));
+    let input = result?;
+
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let (took, answer) = match part {
+                Part::One => took::took(|| S::part_one(&input).map(|a| a.to_string())),
+                Part::Two => took::took(|| S::part_two(&input).map(|a| a.to_string())),
+            };
+            PartReport {
+                part: *part,
+                answer,
+                took,
+            }
+        })
+        .collect();
+
+    Ok(DayReport {
+        day: S::DAY,
+        parse,
+        parts,
+    })
+}
+
+/// The `main` of a single day binary: loads the input, solves both parts, checks them against the
+/// answers registry and prints the results, as records when `AOC_FORMAT` asks for them. Fails on
+/// a wrong answer.
+pub fn run<S: Solution>() -> Result<()> {
+    let data = crate::input::load(S::EMBEDDED)?;
+
+    run_on::<S>(&data)
+}
+
+/// Like [`run`], on input the binary has already loaded.
+pub fn run_on<S: Solution>(data: &str) -> Result<()> {
+    let format = Format::from_env()?;
+
+    let report = solve::<S>(data, &Part::BOTH)?;
+    let hash = crate::input::hash(data);
+    let verdicts = Registry::from_env().verify(&report, &hash, S::EMBEDDED)?;
+    let wrong = verdicts
+        .iter()
+        .filter(|verdict| matches!(verdict, Verdict::Wrong { .. }))
+        .count();
+
+    if let Some(format) = format {
+        let mut records = Record::of(&report, &hash);
+        for (record, verdict) in records.iter_mut().zip(&verdicts) {
+            record.verify(verdict);
+        }
+        record::write(format, &records, std::io::stdout().lock())?;
+        report
+            .parts
+            .into_iter()
+            .try_for_each(|part| part.answer.map(drop))?;
+    } else {
+        println!("Time spent parsing: {}", report.parse);
+        for (part, verdict) in report.parts.into_iter().zip(&verdicts) {
+            match verdict {
+                Verdict::Unverified => println!("Result part {}: {}", part.part, part.answer?),
+                verdict => println!("Result part {}: {} ({verdict})", part.part, part.answer?),
+            }
+            println!("Time spent: {}", part.took);
+        }
+    }
+
+    match wrong {
+        0 => Ok(()),
+        wrong => Err(Error::msg(format!("{wrong} wrong answer(s)"))),
+    }
+}
@@ -0,0 +1,96 @@
+//! Statistical benchmarks of every day's parser and parts, on the sample and the full input.
+//!
+//! Full inputs are read from `AOC_INPUT_DIR` (default `inputs/`) as `day<N>.txt`, falling back
+//! to the embedded input; days without one only run on their sample. To catch regressions, save
+//! a baseline before a change and compare against it afterwards:
+//!
+//! ```text
+//! cargo bench -p aoc -- --save-baseline before
+//! cargo bench -p aoc -- --baseline before
+//! ```
+
+use aoc_core::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::{env, fs, hint::black_box, path::PathBuf};
+
+fn workspace() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// The inputs of a day by label: the sample next to its sources and the full input, if present.
+fn inputs<S: Solution>() -> Vec<(&'static str, String)> {
+    let sample = workspace().join(format!("day{}/src/test.txt", S::DAY));
+    let dir = env::var_os("AOC_INPUT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace().join("inputs"));
+    let full = fs::read_to_string(dir.join(format!("day{}.txt", S::DAY)))
+        .ok()
+        .or_else(|| S::EMBEDDED.map(str::to_string));
+
+    let mut inputs = Vec::new();
+    if let Ok(sample) = fs::read_to_string(sample) {
+        inputs.push(("test", sample));
+    }
+    if let Some(full) = full {
+        inputs.push(("full", full));
+    }
+    inputs
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+
+    for (label, data) in inputs::<S>() {
+        let input = match S::parse(&data) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping {label} input of day {}: {e}", S::DAY);
+                continue;
+            }
+        };
+
+        group.bench_with_input(
+            BenchmarkId::new("parse", label),
+            data.as_str(),
+            |b, data| b.iter(|| S::parse(black_box(data))),
+        );
+        // a part that fails on an input, such as a sample too small for it, is not worth timing
+        if S::part_one(&input).is_ok() {
+            group.bench_with_input(BenchmarkId::new("part_one", label), &input, |b, input| {
+                b.iter(|| S::part_one(black_box(input)))
+            });
+        }
+        if S::part_two(&input).is_ok() {
+            group.bench_with_input(BenchmarkId::new("part_two", label), &input, |b, input| {
+                b.iter(|| S::part_two(black_box(input)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    days,
+    bench_day::<day1::Day1>,
+    bench_day::<day2::Day2>,
+    bench_day::<day3::Day3>,
+    bench_day::<day4::Day4>,
+    bench_day::<day5::Day5>,
+    bench_day::<day6::Day6>,
+    bench_day::<day7::Day7>,
+    bench_day::<day8::Day8>,
+    bench_day::<day9::Day9>,
+    bench_day::<day10::Day10>,
+    bench_day::<day11::Day11>,
+    bench_day::<day12::Day12>,
+    bench_day::<day13::Day13>,
+    bench_day::<day14::Day14>,
+    bench_day::<day15::Day15>,
+    bench_day::<day16::Day16>,
+    bench_day::<day17::Day17>,
+    bench_day::<day18::Day18>,
+    bench_day::<day20::Day20>,
+    bench_day::<day21::Day21>,
+);
+criterion_main!(days);
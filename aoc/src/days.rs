@@ -0,0 +1,108 @@
+use anyhow::{Error, Result};
+use aoc_core::solution::Entry;
+use std::{ops::RangeInclusive, str::FromStr};
+
+/// Every day that has a solution, in day order.
+pub fn registry() -> Vec<Entry> {
+    vec![
+        Entry::of::<day1::Day1>(),
+        Entry::of::<day2::Day2>(),
+        Entry::of::<day3::Day3>(),
+        Entry::of::<day4::Day4>(),
+        Entry::of::<day5::Day5>(),
+        Entry::of::<day6::Day6>(),
+        Entry::of::<day7::Day7>(),
+        Entry::of::<day8::Day8>(),
+        Entry::of::<day9::Day9>(),
+        Entry::of::<day10::Day10>(),
+        Entry::of::<day11::Day11>(),
+        Entry::of::<day12::Day12>(),
+        Entry::of::<day13::Day13>(),
+        Entry::of::<day14::Day14>(),
+        Entry::of::<day15::Day15>(),
+        Entry::of::<day16::Day16>(),
+        Entry::of::<day17::Day17>(),
+        Entry::of::<day18::Day18>(),
+        Entry::of::<day20::Day20>(),
+        Entry::of::<day21::Day21>(),
+    ]
+}
+
+/// The days selected on the command line: `all`, a single day or an inclusive range such as `3-7` or `3..7`.
+#[derive(Clone, Debug)]
+pub enum Days {
+    All,
+    Single(u8),
+    Range(RangeInclusive<u8>),
+}
+
+impl Days {
+    pub fn select(&self, registry: &[Entry]) -> Result<Vec<Entry>> {
+        match self {
+            Days::All => Ok(registry.to_vec()),
+            Days::Single(day) => registry
+                .iter()
+                .find(|entry| entry.day == *day)
+                .map(|entry| vec![*entry])
+                .ok_or_else(|| Error::msg(format!("Day {day} has no solution"))),
+            Days::Range(range) => Ok(registry
+                .iter()
+                .filter(|entry| range.contains(&entry.day))
+                .copied()
+                .collect()),
+        }
+    }
+}
+
+impl FromStr for Days {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "all" {
+            return Ok(Days::All);
+        }
+
+        let range = s.split_once("..").or_else(|| s.split_once('-'));
+        match range {
+            Some((from, to)) => {
+                let from = from.parse::<u8>()?;
+                let to = to.trim_start_matches('=').parse::<u8>()?;
+                if from > to {
+                    return Err(Error::msg(format!("Range {s} is empty")));
+                }
+                Ok(Days::Range(from..=to))
+            }
+            None => Ok(Days::Single(s.parse()?)),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "embedded-input"))]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+    use aoc_core::{
+        answers::{Registry, Verdict},
+        input, Part,
+    };
+
+    #[test]
+    fn test_embedded_answers() -> Result<()> {
+        let answers = Registry::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../answers"));
+
+        for entry in registry() {
+            let data = entry.embedded.context("Every day embeds its input")?;
+            let report = (entry.solve)(data, &Part::BOTH)?;
+            let verdicts = answers.verify(&report, &input::hash(data), entry.embedded)?;
+
+            assert_eq!(
+                verdicts,
+                [Verdict::Correct, Verdict::Correct],
+                "day {}",
+                entry.day
+            );
+        }
+
+        Ok(())
+    }
+}
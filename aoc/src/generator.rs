@@ -0,0 +1,703 @@
+use anyhow::{Error, Result};
+use rand::{
+    rngs::StdRng,
+    seq::{index, SliceRandom},
+    Rng,
+};
+use std::fmt::Write;
+
+/// Writes a random input for `day` in the exact format of its puzzle.
+///
+/// `size` scales the input the way that fits the day: the number of lines or entries for list
+/// inputs, the side of the grid for grid inputs and the nesting depth for day 16. Without a size
+/// the input is about as large as the real one.
+pub fn generate(day: u8, size: Option<usize>, rng: &mut StdRng) -> Result<String> {
+    let generator: fn(&mut StdRng, usize) -> String = match day {
+        1 => depths,
+        2 => commands,
+        3 => diagnostics,
+        4 => bingo,
+        5 => vents,
+        6 => lanternfish,
+        7 => crabs,
+        8 => displays,
+        9 => heightmap,
+        10 => navigation,
+        11 => octopuses,
+        12 => caves,
+        13 => transparent_paper,
+        14 => polymer,
+        15 => risk_grid,
+        16 => transmission,
+        17 => target_area,
+        18 => snailfish,
+        20 => image,
+        21 => starting_positions,
+        _ => return Err(Error::msg(format!("Day {day} has no input generator"))),
+    };
+    let size = size.unwrap_or(match day {
+        1 => 2000,
+        2..=3 => 1000,
+        4 | 9 | 15 | 20 => 100,
+        5 | 8 | 10 => 500,
+        6 => 300,
+        7 => 1000,
+        11 => 10,
+        12 => 10,
+        13 => 800,
+        14 => 20,
+        16 => 6,
+        17 => 150,
+        18 => 100,
+        _ => 0,
+    });
+
+    Ok(generator(rng, size.max(1)))
+}
+
+fn join<T: ToString>(items: impl IntoIterator<Item = T>, separator: &str) -> String {
+    items
+        .into_iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+fn digit_grid(rng: &mut StdRng, side: usize, digits: std::ops::RangeInclusive<u32>) -> String {
+    (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| char::from_digit(rng.gen_range(digits.clone()), 10).unwrap_or('0'))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Day 1: a sonar sweep drifting deeper with some noise.
+fn depths(rng: &mut StdRng, size: usize) -> String {
+    let mut depth: i32 = rng.gen_range(100..200);
+    let depths = (0..size).map(|_| {
+        depth = (depth + rng.gen_range(-10..=15)).clamp(0, u16::MAX as i32);
+        depth
+    });
+
+    join(depths.collect::<Vec<i32>>(), "\n")
+}
+
+/// Day 2: course commands that never take the submarine above the surface.
+fn commands(rng: &mut StdRng, size: usize) -> String {
+    let mut depth = 0;
+    let commands = (0..size)
+        .map(|_| {
+            let value = rng.gen_range(1..10);
+            match rng.gen_range(0..3) {
+                0 => format!("forward {value}"),
+                1 if depth >= value => {
+                    depth -= value;
+                    format!("up {value}")
+                }
+                _ => {
+                    depth += value;
+                    format!("down {value}")
+                }
+            }
+        })
+        .collect::<Vec<String>>();
+
+    commands.join("\n")
+}
+
+/// Day 3: distinct binary numbers, 12 bits wide unless more are needed to keep them distinct.
+fn diagnostics(rng: &mut StdRng, size: usize) -> String {
+    let width = (usize::BITS - size.leading_zeros()).max(12) as usize;
+    let numbers = index::sample(rng, 1 << width, size)
+        .into_iter()
+        .map(|n| format!("{n:0width$b}"));
+
+    join(numbers, "\n")
+}
+
+/// Day 4: every number up to 99 called in random order and `size` cards of distinct numbers.
+fn bingo(rng: &mut StdRng, size: usize) -> String {
+    let mut called_numbers = (0..100).collect::<Vec<u8>>();
+    called_numbers.shuffle(rng);
+
+    let cards = (0..size).map(|_| {
+        let numbers = index::sample(rng, 100, 25).into_vec();
+        numbers
+            .chunks(5)
+            .map(|row| join(row.iter().map(|n| format!("{n:>2}")), " ") + "\n")
+            .collect::<String>()
+    });
+
+    format!(
+        "{}\n\n{}",
+        join(called_numbers, ","),
+        join(cards.collect::<Vec<String>>(), "\n")
+    )
+}
+
+/// Day 5: horizontal, vertical and diagonal vent lines on a field twice as wide as there are lines.
+fn vents(rng: &mut StdRng, size: usize) -> String {
+    let max = (size * 2).max(10) as i64 - 1;
+    let lines = (0..size).map(|_| loop {
+        let (x1, y1) = (rng.gen_range(0..=max), rng.gen_range(0..=max));
+        let length = rng.gen_range(1..=max / 2);
+        let (dx, dy) = match rng.gen_range(0..4) {
+            0 => (1, 0),
+            1 => (0, 1),
+            2 => (1, 1),
+            _ => (1, -1),
+        };
+        let sign = if rng.gen_bool(0.5) { 1 } else { -1 };
+        let (x2, y2) = (x1 + sign * dx * length, y1 + sign * dy * length);
+        if (0..=max).contains(&x2) && (0..=max).contains(&y2) {
+            break format!("{x1},{y1} -> {x2},{y2}");
+        }
+    });
+
+    join(lines.collect::<Vec<String>>(), "\n")
+}
+
+/// Day 6: the timers of the initial lanternfish.
+fn lanternfish(rng: &mut StdRng, size: usize) -> String {
+    join(
+        (0..size).map(|_| rng.gen_range(1..=5)).collect::<Vec<u8>>(),
+        ",",
+    )
+}
+
+/// Day 7: crab positions, bunched towards the lower end like the real ones.
+fn crabs(rng: &mut StdRng, size: usize) -> String {
+    let max = size.max(10) as f64 * 2.0;
+    let positions = (0..size).map(|_| (rng.gen_range(0.0..1.0f64).powi(2) * max) as u32);
+
+    join(positions.collect::<Vec<u32>>(), ",")
+}
+
+/// Day 8: all ten digits and four output digits of displays with scrambled wiring.
+fn displays(rng: &mut StdRng, size: usize) -> String {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+
+    fn scramble(rng: &mut StdRng, wires: &[char], digit: usize) -> String {
+        let mut segments = DIGITS[digit]
+            .bytes()
+            .map(|segment| wires[(segment - b'a') as usize])
+            .collect::<Vec<char>>();
+        segments.shuffle(rng);
+        segments.into_iter().collect()
+    }
+
+    let lines = (0..size).map(|_| {
+        let mut wires = "abcdefg".chars().collect::<Vec<char>>();
+        wires.shuffle(rng);
+
+        let mut signals = (0..10).collect::<Vec<usize>>();
+        signals.shuffle(rng);
+        let signals = signals
+            .into_iter()
+            .map(|digit| scramble(rng, &wires, digit))
+            .collect::<Vec<String>>();
+        let outputs = (0..4)
+            .map(|_| {
+                let digit = rng.gen_range(0..10);
+                scramble(rng, &wires, digit)
+            })
+            .collect::<Vec<String>>();
+
+        format!("{} | {}", signals.join(" "), outputs.join(" "))
+    });
+
+    join(lines.collect::<Vec<String>>(), "\n")
+}
+
+/// Day 9: a square grid of basins, each sloping up from a single low point to ridges of height 9.
+fn heightmap(rng: &mut StdRng, size: usize) -> String {
+    let low_points = (0..(size * size / 60).max(1))
+        .map(|_| (rng.gen_range(0..size), rng.gen_range(0..size)))
+        .collect::<Vec<(usize, usize)>>();
+
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    let mut distances = low_points
+                        .iter()
+                        .map(|(lx, ly)| lx.abs_diff(x) + ly.abs_diff(y))
+                        .collect::<Vec<usize>>();
+                    distances.sort_unstable();
+                    // cells about as far from two low points form the ridge between their basins
+                    let height = match distances.as_slice() {
+                        [nearest, second, ..] if second - nearest <= 1 => 9,
+                        [nearest, ..] => (*nearest).min(8),
+                        [] => 9,
+                    };
+                    char::from_digit(height as u32, 10).unwrap_or('9')
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Day 15: a square grid of risk levels.
+fn risk_grid(rng: &mut StdRng, size: usize) -> String {
+    digit_grid(rng, size, 1..=9)
+}
+
+/// Day 10: chunks that are either corrupted or incomplete, with an odd number of incomplete ones
+/// so they have a middle score. Chunks nest at most as deep as in the real input, keeping the
+/// completion scores in range.
+fn navigation(rng: &mut StdRng, size: usize) -> String {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    const MAX_DEPTH: usize = 16;
+
+    let mut incomplete = 0;
+    let lines = (0..size).map(|i| {
+        let last = i + 1 == size;
+        let corrupted = if last {
+            incomplete % 2 == 1
+        } else {
+            rng.gen_bool(0.5)
+        };
+        if !corrupted {
+            incomplete += 1;
+        }
+
+        let length = rng.gen_range(20..110);
+        let mut line = String::new();
+        let mut open: Vec<char> = Vec::new();
+        while line.len() < length || open.is_empty() {
+            match open.last() {
+                Some(closer) if open.len() == MAX_DEPTH || rng.gen_bool(0.5) => {
+                    line.push(*closer);
+                    open.pop();
+                }
+                _ => {
+                    let (opener, closer) = PAIRS[rng.gen_range(0..4)];
+                    line.push(opener);
+                    open.push(closer);
+                }
+            }
+        }
+
+        if corrupted {
+            let expected = open.last().copied();
+            let (_, wrong) = *PAIRS
+                .iter()
+                .filter(|(_, closer)| Some(*closer) != expected)
+                .collect::<Vec<&(char, char)>>()
+                .choose(rng)
+                .copied()
+                .unwrap_or(&PAIRS[0]);
+            line.push(wrong);
+        }
+
+        line
+    });
+
+    join(lines.collect::<Vec<String>>(), "\n")
+}
+
+/// Day 11: a square grid of octopus energy levels that all flash at once within 1000 steps, as
+/// many random grids never do.
+///
+/// Large grids rarely synchronise, so after a few tries the levels get drawn from a narrower
+/// range, down to a grid of ones that flashes all at once on the ninth step.
+fn octopuses(rng: &mut StdRng, size: usize) -> String {
+    const ATTEMPTS: usize = 5;
+
+    for highest in (2..=9).rev() {
+        for _ in 0..ATTEMPTS {
+            let grid = digit_grid(rng, size, 1..=highest);
+            if synchronises(&grid, 1000) {
+                return grid;
+            }
+        }
+    }
+
+    digit_grid(rng, size, 1..=1)
+}
+
+fn synchronises(grid: &str, steps: usize) -> bool {
+    let mut levels = grid
+        .lines()
+        .map(|line| line.bytes().map(|b| b - b'0').collect::<Vec<u8>>())
+        .collect::<Vec<Vec<u8>>>();
+    let (width, height) = (levels[0].len(), levels.len());
+
+    (0..steps).any(|_| {
+        let mut flashing = Vec::new();
+        for (y, row) in levels.iter_mut().enumerate() {
+            for (x, level) in row.iter_mut().enumerate() {
+                *level += 1;
+                if *level == 10 {
+                    flashing.push((x, y));
+                }
+            }
+        }
+
+        let mut flashes = 0;
+        while let Some((x, y)) = flashing.pop() {
+            flashes += 1;
+            let rows = y.saturating_sub(1)..(y + 2).min(height);
+            for (row, ny) in levels[rows.clone()].iter_mut().zip(rows) {
+                let columns = x.saturating_sub(1)..(x + 2).min(width);
+                for (level, nx) in row[columns.clone()].iter_mut().zip(columns) {
+                    *level += 1;
+                    if *level == 10 {
+                        flashing.push((nx, ny));
+                    }
+                }
+            }
+        }
+        levels
+            .iter_mut()
+            .flatten()
+            .filter(|l| **l > 9)
+            .for_each(|l| *l = 0);
+
+        flashes == width * height
+    })
+}
+
+/// Day 12: `size` caves besides start and end, without neighbouring big caves so the number of
+/// paths stays finite.
+fn caves(rng: &mut StdRng, size: usize) -> String {
+    let mut small = Vec::new();
+    let mut big = Vec::new();
+    while small.len() + big.len() < size {
+        let is_big = (small.len() + big.len()) % 4 == 3;
+        let base = if is_big { b'A' } else { b'a' };
+        let name = (0..2)
+            .map(|_| (base + rng.gen_range(0..26)) as char)
+            .collect::<String>();
+        if small.contains(&name) || big.contains(&name) {
+            continue;
+        }
+        if is_big {
+            big.push(name);
+        } else {
+            small.push(name);
+        }
+    }
+
+    // a chain from start to end through every small cave keeps them all connected
+    small.shuffle(rng);
+    let small = [vec!["start".to_string()], small, vec!["end".to_string()]].concat();
+    let mut edges = small
+        .windows(2)
+        .map(|w| (w[0].clone(), w[1].clone()))
+        .collect::<Vec<(String, String)>>();
+    for cave in &big {
+        for neighbour in index::sample(rng, small.len(), 2.min(small.len())) {
+            edges.push((cave.clone(), small[neighbour].clone()));
+        }
+    }
+    for _ in 0..size / 2 {
+        let from = &small[rng.gen_range(0..small.len())];
+        let to = &small[rng.gen_range(0..small.len())];
+        let linked = edges
+            .iter()
+            .any(|(a, b)| (a == from && b == to) || (a == to && b == from));
+        if from != to && !linked {
+            edges.push((from.clone(), to.clone()));
+        }
+    }
+    edges.shuffle(rng);
+
+    join(
+        edges.into_iter().map(|(from, to)| format!("{from}-{to}")),
+        "\n",
+    )
+}
+
+/// Day 13: `size` dots folded in half along alternating axes down to a 40x6 code.
+fn transparent_paper(rng: &mut StdRng, size: usize) -> String {
+    let (mut width, mut height) = (40, 6);
+    let mut folds = Vec::new();
+    for i in 0..5 {
+        if i % 2 == 0 {
+            folds.push(('x', width));
+            width = width * 2 + 1;
+        } else {
+            folds.push(('y', height));
+            height = height * 2 + 1;
+        }
+    }
+    folds.reverse();
+
+    // no dot ever lies on a fold line
+    let on_fold = |x: i32, y: i32| {
+        folds
+            .iter()
+            .any(|(axis, at)| if *axis == 'x' { x == *at } else { y == *at })
+    };
+    let dots = (0..size).map(|_| loop {
+        let (x, y) = (rng.gen_range(0..width), rng.gen_range(0..height));
+        if !on_fold(x, y) {
+            break format!("{x},{y}");
+        }
+    });
+    let dots = join(dots.collect::<Vec<String>>(), "\n");
+    let folds = join(
+        folds
+            .iter()
+            .map(|(axis, at)| format!("fold along {axis}={at}")),
+        "\n",
+    );
+
+    format!("{dots}\n\n{folds}")
+}
+
+/// Day 14: a template of `size` elements and insertion rules for every pair of ten elements.
+fn polymer(rng: &mut StdRng, size: usize) -> String {
+    const ELEMENTS: [char; 10] = ['B', 'C', 'F', 'H', 'K', 'N', 'O', 'P', 'S', 'V'];
+
+    let template = (0..size)
+        .map(|_| ELEMENTS[rng.gen_range(0..ELEMENTS.len())])
+        .collect::<String>();
+    let mut rules = Vec::new();
+    for a in ELEMENTS {
+        for b in ELEMENTS {
+            let insert = ELEMENTS[rng.gen_range(0..ELEMENTS.len())];
+            rules.push(format!("{a}{b} -> {insert}"));
+        }
+    }
+    rules.shuffle(rng);
+
+    format!("{template}\n\n{}", rules.join("\n"))
+}
+
+/// Day 16: a transmission of operator packets nested `size` levels deep, with values that fit
+/// in a `u64`.
+fn transmission(rng: &mut StdRng, size: usize) -> String {
+    let mut bits = String::new();
+    packet(rng, size, &mut bits);
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+
+    bits.as_bytes()
+        .chunks(4)
+        .map(|nibble| {
+            let value = nibble
+                .iter()
+                .fold(0, |acc, bit| acc * 2 + u32::from(*bit == b'1'));
+            char::from_digit(value, 16)
+                .unwrap_or('0')
+                .to_ascii_uppercase()
+        })
+        .collect()
+}
+
+/// Appends a random packet of at most `depth` levels to `bits` and returns its value.
+fn packet(rng: &mut StdRng, depth: usize, bits: &mut String) -> u64 {
+    let version = rng.gen_range(0..8);
+    if depth <= 1 || rng.gen_bool(0.2) {
+        let bits_used = rng.gen_range(4..20);
+        let value = rng.gen_range(0..1u64 << bits_used);
+        let groups = (0..16)
+            .rev()
+            .map(|i| (value >> (i * 4)) & 0xf)
+            .skip_while(|group| *group == 0)
+            .collect::<Vec<u64>>();
+        let groups = if groups.is_empty() { vec![0] } else { groups };
+        let _ = write!(bits, "{version:03b}100");
+        for (i, group) in groups.iter().enumerate() {
+            let more = u8::from(i + 1 < groups.len());
+            let _ = write!(bits, "{more}{group:04b}");
+        }
+        return value;
+    }
+
+    let comparison = rng.gen_bool(0.25);
+    let children = if comparison { 2 } else { rng.gen_range(1..=4) };
+    let mut sub_bits = String::new();
+    let values = (0..children)
+        .map(|_| packet(rng, depth - 1, &mut sub_bits))
+        .collect::<Vec<u64>>();
+
+    let product = values.iter().try_fold(1u64, |acc, v| acc.checked_mul(*v));
+    let sum = values.iter().try_fold(0u64, |acc, v| acc.checked_add(*v));
+    let (type_id, value) = match (comparison, rng.gen_range(0..4)) {
+        (true, 0) => (5, u64::from(values[0] > values[1])),
+        (true, 1) => (6, u64::from(values[0] < values[1])),
+        (true, _) => (7, u64::from(values[0] == values[1])),
+        (false, 1) if product.is_some_and(|p| p < 1 << 40) => (1, product.unwrap_or(0)),
+        (false, 2) => (2, values.iter().copied().min().unwrap_or(0)),
+        (false, 3) => (3, values.iter().copied().max().unwrap_or(0)),
+        (false, _) => match sum {
+            Some(sum) if sum < 1 << 40 => (0, sum),
+            _ => (2, values.iter().copied().min().unwrap_or(0)),
+        },
+    };
+
+    let _ = write!(bits, "{version:03b}{type_id:03b}");
+    if rng.gen_bool(0.5) {
+        let _ = write!(bits, "1{children:011b}");
+    } else {
+        let _ = write!(bits, "0{:015b}", sub_bits.len());
+    }
+    bits.push_str(&sub_bits);
+
+    value
+}
+
+/// Day 17: a target area right of and below the launcher, reaching as far as `size`.
+fn target_area(rng: &mut StdRng, size: usize) -> String {
+    let reach = size.max(30) as i32;
+    let x1 = rng.gen_range(reach / 3..reach - 10);
+    let x2 = rng.gen_range(x1 + 5..=reach);
+    let y1 = rng.gen_range(-reach / 2..-10);
+    let y2 = rng.gen_range(y1 + 5..=-5);
+
+    format!("target area: x={x1}..{x2}, y={y1}..{y2}")
+}
+
+/// Day 18: `size` reduced snailfish numbers.
+fn snailfish(rng: &mut StdRng, size: usize) -> String {
+    fn pair(rng: &mut StdRng, depth: usize) -> String {
+        let element = |rng: &mut StdRng| {
+            if depth < 4 && rng.gen_bool(0.6) {
+                pair(rng, depth + 1)
+            } else {
+                rng.gen_range(0..10).to_string()
+            }
+        };
+        let left = element(rng);
+        let right = element(rng);
+        format!("[{left},{right}]")
+    }
+
+    join(
+        (0..size).map(|_| pair(rng, 1)).collect::<Vec<String>>(),
+        "\n",
+    )
+}
+
+/// Day 20: an enhancement algorithm and a square image of side `size`; an algorithm lighting
+/// dark surroundings always darkens them again, so the lit pixels stay finite.
+fn image(rng: &mut StdRng, size: usize) -> String {
+    let pixel = |lit: bool| if lit { '#' } else { '.' };
+    let mut algorithm = (0..512)
+        .map(|_| pixel(rng.gen_bool(0.5)))
+        .collect::<Vec<char>>();
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+
+    let image = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| pixel(rng.gen_bool(0.5)))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>();
+
+    format!(
+        "{}\n\n{}",
+        algorithm.into_iter().collect::<String>(),
+        image.join("\n")
+    )
+}
+
+/// Day 21: the starting positions of both players; there is nothing to scale.
+fn starting_positions(rng: &mut StdRng, _: usize) -> String {
+    let lines = (1..=2).map(|player| {
+        let position = rng.gen_range(1..=10);
+        format!("Player {player} starting position: {position}")
+    });
+
+    join(lines.collect::<Vec<String>>(), "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+    use aoc_core::Solution;
+    use rand::SeedableRng;
+
+    /// Parses the inputs of 20 seeds and solves those of the first `solved` ones.
+    fn round_trip<S: Solution>(size: usize, solved: u64) -> Result<()> {
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let data = generate(S::DAY, Some(size), &mut rng)?;
+            let input =
+                S::parse(&data).map_err(|e| Error::new(e).context(format!("seed {seed}")))?;
+            if seed < solved {
+                S::part_one(&input).with_context(|| format!("part one of seed {seed}"))?;
+                S::part_two(&input).with_context(|| format!("part two of seed {seed}"))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        round_trip::<day1::Day1>(50, 5)?;
+        round_trip::<day2::Day2>(50, 5)?;
+        round_trip::<day3::Day3>(50, 5)?;
+        round_trip::<day4::Day4>(5, 5)?;
+        round_trip::<day5::Day5>(50, 5)?;
+        round_trip::<day6::Day6>(50, 5)?;
+        round_trip::<day7::Day7>(50, 5)?;
+        round_trip::<day8::Day8>(20, 5)?;
+        round_trip::<day9::Day9>(10, 5)?;
+        round_trip::<day10::Day10>(20, 5)?;
+        round_trip::<day11::Day11>(10, 5)?;
+        round_trip::<day12::Day12>(6, 5)?;
+        round_trip::<day13::Day13>(50, 5)?;
+        round_trip::<day14::Day14>(10, 5)?;
+        round_trip::<day15::Day15>(10, 5)?;
+        round_trip::<day16::Day16>(4, 5)?;
+        // the probe is launched at every velocity whatever the size of the target
+        round_trip::<day17::Day17>(50, 1)?;
+        round_trip::<day18::Day18>(10, 5)?;
+        round_trip::<day20::Day20>(10, 5)?;
+        round_trip::<day21::Day21>(1, 0)
+    }
+
+    #[test]
+    #[ignore = "day 21 plays out every universe, which takes minutes in a debug build"]
+    fn test_round_trip_dirac_dice() -> Result<()> {
+        round_trip::<day21::Day21>(1, 1)
+    }
+
+    #[test]
+    fn test_default_size_solves() -> Result<()> {
+        // the default day 10 input used to nest too deep to score
+        let data = generate(10, None, &mut StdRng::seed_from_u64(7))?;
+        let input = day10::Day10::parse(&data)?;
+
+        day10::Day10::part_two(&input)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_octopus_grid_synchronises() -> Result<()> {
+        // random grids this large used to be drawn over and over without ever synchronising
+        let data = generate(11, Some(60), &mut StdRng::seed_from_u64(1))?;
+        let input = day11::Day11::parse(&data)?;
+
+        assert!(day11::Day11::part_two(&input)? <= 1000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seed_reproducible() -> Result<()> {
+        let first = generate(14, None, &mut StdRng::seed_from_u64(7))?;
+        let second = generate(14, None, &mut StdRng::seed_from_u64(7))?;
+
+        assert_eq!(first, second);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,349 @@
+mod days;
+mod generator;
+mod pool;
+mod table;
+
+use anyhow::{Context, Error, Result};
+use aoc_core::{
+    answers::{self, Registry, Verdict},
+    input::{self, Source},
+    record::{self, Format, Record},
+    solution::{DayReport, Entry},
+    Part,
+};
+use clap::{Args, Parser, Subcommand};
+use days::Days;
+use pool::{Job, Stopped};
+use rand::{rngs::StdRng, SeedableRng};
+use std::{
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+use table::Table;
+use took::Took;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or more days and print a summary of the answers and timings
+    Run(RunArgs),
+    /// Print a random input for a day, in the format of its puzzle
+    Gen(GenArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// A day (14), an inclusive range (3-7 or 3..7) or all
+    days: Days,
+
+    /// Only solve this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file of a single day, - for stdin
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Directory holding the inputs as day<N>.txt
+    #[arg(long, env = "AOC_INPUT_DIR", default_value = "inputs")]
+    input_dir: PathBuf,
+
+    /// Directory of the verified answers, one <input hash>.json per input
+    #[arg(long, env = answers::ANSWERS_VAR, default_value = answers::DEFAULT_DIR)]
+    answers_dir: PathBuf,
+
+    /// Record the answers of inputs without verified ones as correct
+    #[arg(long)]
+    record: bool,
+
+    /// Print one record per day and part as json lines or csv instead of a table
+    #[arg(short, long, env = record::FORMAT_VAR)]
+    format: Option<Format>,
+
+    /// Solve the days at once on this many threads, one per core when no number is given
+    #[arg(short, long, num_args = 0..=1, default_missing_value = "0")]
+    jobs: Option<usize>,
+
+    /// Give up on a day still running after this many seconds and carry on with the others. The
+    /// day can't be stopped, so it keeps its thread and a core busy until the run ends
+    #[arg(short, long, value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    day: u8,
+
+    /// How large the input gets, such as its number of lines or the side of its grid
+    #[arg(short = 'n', long)]
+    size: Option<usize>,
+
+    /// Seed of the generator, random when left out
+    #[arg(short, long)]
+    seed: Option<u64>,
+}
+
+impl RunArgs {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(1) => vec![Part::One],
+            Some(_) => vec![Part::Two],
+            None => Part::BOTH.to_vec(),
+        }
+    }
+
+    fn workers(&self) -> usize {
+        match self.jobs {
+            None => 1,
+            Some(0) => thread::available_parallelism().map_or(1, |n| n.get()),
+            Some(jobs) => jobs,
+        }
+    }
+
+    fn source(&self, entry: &Entry) -> Source {
+        match &self.input {
+            Some(path) if path.as_os_str() == "-" => Source::Stdin,
+            Some(path) => Source::Path(path.clone()),
+            None => {
+                let path = self.input_dir.join(format!("day{}.txt", entry.day));
+                match entry.embedded {
+                    Some(data) if !path.exists() => Source::Embedded(data),
+                    _ => Source::Path(path),
+                }
+            }
+        }
+    }
+}
+
+fn parse_seconds(value: &str) -> Result<Duration> {
+    let seconds = value.parse::<f64>()?;
+    Duration::try_from_secs_f64(seconds).map_err(Error::from)
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(&args),
+        Command::Gen(args) => generate(&args),
+    }
+}
+
+fn generate(args: &GenArgs) -> Result<()> {
+    let seed = args.seed.unwrap_or_else(rand::random);
+    eprintln!("Seed: {seed}");
+
+    let data = generator::generate(args.day, args.size, &mut StdRng::seed_from_u64(seed))?;
+    println!("{data}");
+
+    Ok(())
+}
+
+fn run(args: &RunArgs) -> Result<()> {
+    let entries = args.days.select(&days::registry())?;
+    if args.input.is_some() && entries.len() != 1 {
+        return Err(Error::msg(
+            "--input can only be used when running a single day",
+        ));
+    }
+
+    let parts = args.parts();
+    let registry = Registry::new(&args.answers_dir).recording(args.record);
+    let workers = args.workers();
+    let jobs = entries
+        .iter()
+        .map(|entry| {
+            let (entry, source, parts) = (*entry, args.source(entry), parts.clone());
+            Box::new(move || solve(&entry, source, &parts)) as Job<Solved>
+        })
+        .collect();
+
+    let start = Instant::now();
+    let results = pool::run(jobs, workers, args.timeout);
+    let wall = start.elapsed();
+
+    let outcomes = entries
+        .iter()
+        .zip(results)
+        .map(|(entry, result)| outcome(&registry, entry, result))
+        .collect::<Vec<Outcome>>();
+
+    match args.format {
+        Some(format) => {
+            let records = outcomes
+                .iter()
+                .flat_map(|outcome| outcome.records(&parts))
+                .collect::<Vec<Record>>();
+            record::write(format, &records, std::io::stdout().lock())?;
+        }
+        None => {
+            print!("{}", summary(&parts, &outcomes));
+            print!("{}", timings(&outcomes, wall, workers));
+        }
+    }
+
+    let failures = outcomes
+        .iter()
+        .filter_map(|outcome| outcome.report.as_ref().err())
+        .collect::<Vec<_>>();
+    for error in &failures {
+        eprintln!("{error:#}");
+    }
+    let wrong = outcomes
+        .iter()
+        .flat_map(|outcome| &outcome.verdicts)
+        .filter(|verdict| matches!(verdict, Verdict::Wrong { .. }))
+        .count();
+
+    match (failures.len(), wrong) {
+        (0, 0) => Ok(()),
+        (failed, 0) => Err(Error::msg(format!("{failed} day(s) failed"))),
+        (0, wrong) => Err(Error::msg(format!("{wrong} wrong answer(s)"))),
+        (failed, wrong) => Err(Error::msg(format!(
+            "{failed} day(s) failed, {wrong} wrong answer(s)"
+        ))),
+    }
+}
+
+/// A solved day with the hash of its input and the verdicts on its answers, one per part.
+struct Outcome {
+    day: u8,
+    hash: Option<String>,
+    report: Result<DayReport>,
+    verdicts: Vec<Verdict>,
+}
+
+impl Outcome {
+    fn records(&self, parts: &[Part]) -> Vec<Record> {
+        match &self.report {
+            Ok(report) => {
+                let mut records = Record::of(report, self.hash.as_deref().unwrap_or_default());
+                for (record, verdict) in records.iter_mut().zip(&self.verdicts) {
+                    record.verify(verdict);
+                }
+                records
+            }
+            Err(e) => Record::failed(self.day, parts, e, self.hash.as_deref()),
+        }
+    }
+}
+
+/// The input hash and report of a day, as solved on a worker of the pool.
+type Solved = (Option<String>, Result<DayReport>);
+
+fn solve(entry: &Entry, source: Source, parts: &[Part]) -> Solved {
+    match source
+        .read()
+        .with_context(|| format!("Day {} has no input", entry.day))
+    {
+        Ok(data) => (Some(input::hash(&data)), (entry.solve)(&data, parts)),
+        Err(e) => (None, Err(e)),
+    }
+}
+
+fn outcome(registry: &Registry, entry: &Entry, result: Result<Solved, Stopped>) -> Outcome {
+    let (hash, report) = result.unwrap_or_else(|stopped| {
+        (
+            None,
+            Err(Error::msg(format!("Day {} {stopped}", entry.day))),
+        )
+    });
+
+    let (report, verdicts) = match (report, &hash) {
+        (Ok(report), Some(hash)) => match registry.verify(&report, hash, entry.embedded) {
+            Ok(verdicts) => (Ok(report), verdicts),
+            Err(e) => (Err(e), Vec::new()),
+        },
+        (report, _) => (report, Vec::new()),
+    };
+
+    Outcome {
+        day: entry.day,
+        hash,
+        report,
+        verdicts,
+    }
+}
+
+fn summary(parts: &[Part], outcomes: &[Outcome]) -> Table {
+    let mut header = vec!["Day".to_string(), "Parse".to_string()];
+    for part in parts {
+        header.push(format!("Part {part}"));
+        header.push("Time".to_string());
+    }
+    let mut table = Table::new(&header);
+
+    let mut total = Duration::ZERO;
+    for outcome in outcomes {
+        let mut row = vec![outcome.day.to_string()];
+        match &outcome.report {
+            Ok(report) => {
+                row.push(report.parse.to_string());
+                total += *report.parse.as_std();
+                for (part, verdict) in report.parts.iter().zip(&outcome.verdicts) {
+                    let answer = match (&part.answer, verdict) {
+                        (Ok(answer), Verdict::Unverified) => answer.clone(),
+                        (Ok(answer), verdict) => format!("{answer} ({verdict})"),
+                        (Err(e), _) => format!("error: {e}"),
+                    };
+                    row.push(answer);
+                    row.push(part.took.to_string());
+                    total += *part.took.as_std();
+                }
+            }
+            Err(_) => row.push("failed".to_string()),
+        }
+        table.push(row);
+    }
+
+    let mut footer = vec!["Total".to_string(), String::new()];
+    footer.resize(header.len() - 1, String::new());
+    footer.push(Took::from_std(total).to_string());
+    table.push(footer);
+
+    table
+}
+
+/// Time spent solving a day: its parse and every part.
+fn busy(report: &DayReport) -> Duration {
+    report
+        .parts
+        .iter()
+        .map(|part| *part.took.as_std())
+        .fold(*report.parse.as_std(), |total, took| total + took)
+}
+
+/// How the wall-clock time of the run compares to the wall-clock times of the days summed up, and
+/// which day took the largest share of that sum.
+fn timings(outcomes: &[Outcome], wall: Duration, workers: usize) -> String {
+    let days = outcomes
+        .iter()
+        .filter_map(|outcome| outcome.report.as_ref().ok())
+        .map(|report| (report.day, busy(report)))
+        .collect::<Vec<_>>();
+    let total = days.iter().map(|(_, took)| *took).sum::<Duration>();
+
+    let mut timings = format!(
+        "\nWall time: {} on {workers} thread(s), summed solve time: {}\n",
+        Took::from_std(wall),
+        Took::from_std(total)
+    );
+    if let Some((day, took)) = days.iter().max_by_key(|(_, took)| *took)
+        && !total.is_zero()
+    {
+        timings += &format!(
+            "Slowest: day {day}, {} ({:.0}% of the summed solve time)\n",
+            Took::from_std(*took),
+            100.0 * took.as_secs_f64() / total.as_secs_f64()
+        );
+    }
+
+    timings
+}
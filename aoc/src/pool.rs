@@ -0,0 +1,130 @@
+use std::{
+    collections::VecDeque,
+    fmt::{Display, Formatter},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::Duration,
+};
+use took::Took;
+
+/// A unit of work handed to the pool.
+pub type Job<T> = Box<dyn FnOnce() -> T + Send + 'static>;
+
+/// Why a job of the pool has no result.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stopped {
+    TimedOut(Duration),
+    Panicked,
+}
+
+impl Display for Stopped {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stopped::TimedOut(timeout) => write!(f, "timed out after {}", Took::from_std(*timeout)),
+            Stopped::Panicked => write!(f, "panicked"),
+        }
+    }
+}
+
+/// Runs the jobs on `workers` threads and returns their results in the order of the jobs.
+///
+/// Every job runs on a thread of its own, watched by a worker. Threads can't be killed, so a job
+/// still running after `timeout` is left behind, still using a core, until the process exits,
+/// and its worker moves on to the next job.
+pub fn run<T: Send + 'static>(
+    jobs: Vec<Job<T>>,
+    workers: usize,
+    timeout: Option<Duration>,
+) -> Vec<Result<T, Stopped>> {
+    let count = jobs.len();
+    let queue = Arc::new(Mutex::new(
+        jobs.into_iter().enumerate().collect::<VecDeque<_>>(),
+    ));
+    let (results_tx, results_rx) = mpsc::channel();
+
+    let workers = (0..workers.clamp(1, count.max(1)))
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            let results = results_tx.clone();
+            thread::spawn(move || {
+                while let Some((index, job)) = next(&queue) {
+                    let (tx, rx) = mpsc::channel();
+                    thread::spawn(move || tx.send(job()));
+                    let result = match timeout {
+                        Some(timeout) => rx.recv_timeout(timeout).map_err(|e| match e {
+                            mpsc::RecvTimeoutError::Timeout => Stopped::TimedOut(timeout),
+                            mpsc::RecvTimeoutError::Disconnected => Stopped::Panicked,
+                        }),
+                        None => rx.recv().map_err(|_| Stopped::Panicked),
+                    };
+                    if results.send((index, result)).is_err() {
+                        break;
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    drop(results_tx);
+
+    let mut results = (0..count)
+        .map(|_| Err(Stopped::Panicked))
+        .collect::<Vec<_>>();
+    for (index, result) in results_rx {
+        results[index] = result;
+    }
+    for worker in workers {
+        let _ = worker.join();
+    }
+
+    results
+}
+
+fn next<T>(queue: &Mutex<VecDeque<(usize, Job<T>)>>) -> Option<(usize, Job<T>)> {
+    queue.lock().ok()?.pop_front()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order() {
+        let jobs = (0..8u64)
+            .map(|i| {
+                Box::new(move || {
+                    thread::sleep(Duration::from_millis(8 - i));
+                    i
+                }) as Job<u64>
+            })
+            .collect();
+
+        let results = run(jobs, 3, None);
+
+        assert_eq!(results, (0..8).map(Ok).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_timeout_and_panic() {
+        let timeout = Duration::from_secs(1);
+        let jobs: Vec<Job<u8>> = vec![
+            Box::new(|| 1),
+            Box::new(|| loop {
+                thread::sleep(Duration::from_millis(10));
+            }),
+            Box::new(|| panic!("boom")),
+            Box::new(|| 4),
+        ];
+
+        let results = run(jobs, 2, Some(timeout));
+
+        assert_eq!(
+            results,
+            vec![
+                Ok(1),
+                Err(Stopped::TimedOut(timeout)),
+                Err(Stopped::Panicked),
+                Ok(4)
+            ]
+        );
+    }
+}
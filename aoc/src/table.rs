@@ -0,0 +1,62 @@
+use std::fmt::{Display, Formatter};
+
+/// A plain text table with left-aligned columns.
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: ToString>(header: &[S]) -> Self {
+        Table {
+            header: header.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        (0..self.header.len())
+            .map(|i| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(i))
+                    .chain([&self.header[i]])
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let widths = self.widths();
+        let write_row = |f: &mut Formatter<'_>, row: &[String]| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<String>>()
+                .join(" | ");
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        write_row(f, &self.header)?;
+        let separator = widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<String>>()
+            .join("-+-");
+        writeln!(f, "{separator}")?;
+        for row in &self.rows {
+            write_row(f, row)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+mod sweep;
+
+pub use sweep::{analyze, sweep, Sweep, SweepReport};
+
+use anyhow::Result;
+use aoc_core::{
+    parse::{finish, IResult},
+    ParseError, Solution,
+};
+use nom::{
+    character::complete::{self, line_ending},
+    multi::separated_list1,
+    Parser,
+};
+use std::num::NonZeroUsize;
+
+const THREE: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Vec<u64>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        Ok(part_two(input))
+    }
+}
+
+fn part_one(input: &[u64]) -> usize {
+    sweep(input, NonZeroUsize::MIN).increases
+}
+
+fn part_two(input: &[u64]) -> usize {
+    sweep(input, THREE).increases
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<u64>> {
+    separated_list1(line_ending, complete::u64).parse(input)
+}
+
+fn parse_input(input: impl AsRef<str>) -> Result<Vec<u64>, ParseError> {
+    let input = input.as_ref();
+
+    finish(Day1::DAY, input, parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse_input(TESTDATA)?), 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse_input(TESTDATA)?), 5);
+
+        Ok(())
+    }
+
+    /// Part two the long way: sum every window of three and count the increases.
+    fn part_two_naive(input: &[u64]) -> usize {
+        let sums = input
+            .windows(3)
+            .map(|w| w.iter().map(|d| *d as u128).sum::<u128>())
+            .collect::<Vec<u128>>();
+        sums.windows(2).filter(|s| s[1] > s[0]).count()
+    }
+
+    /// Any depth that fits in a `u16`, the range of the puzzle inputs.
+    fn depth() -> impl Strategy<Value = u64> {
+        any::<u16>().prop_map(u64::from)
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(depths in vec(any::<u64>(), 1..200)) {
+            let data = depths.iter().map(u64::to_string).collect::<Vec<String>>().join("\n");
+
+            prop_assert_eq!(parse_input(data)?, depths);
+        }
+
+        #[test]
+        fn test_part_one_bounds(depths in vec(depth(), 1..200)) {
+            prop_assert!(part_one(&depths) < depths.len());
+        }
+
+        #[test]
+        fn test_part_two_over_window_sums(depths in vec(depth(), 1..200)) {
+            prop_assert_eq!(part_two(&depths), part_two_naive(&depths));
+        }
+
+        #[test]
+        fn test_part_two_sorted(mut depths in vec(depth(), 1..200)) {
+            depths.sort_unstable();
+            depths.dedup();
+
+            prop_assert_eq!(part_two(&depths), depths.len().saturating_sub(3));
+        }
+
+        #[test]
+        fn test_sweep_over_window_sums(depths in vec(any::<u64>(), 1..200), window in (1..10usize).prop_map(|w| NonZeroUsize::new(w).unwrap())) {
+            let sums = depths
+                .windows(window.get())
+                .map(|w| w.iter().map(|d| *d as u128).sum::<u128>())
+                .collect::<Vec<u128>>();
+            let report = sweep(&depths, window);
+
+            prop_assert_eq!(report.increases, sums.windows(2).filter(|s| s[1] > s[0]).count());
+            prop_assert_eq!(report.decreases, sums.windows(2).filter(|s| s[1] < s[0]).count());
+            prop_assert_eq!(report.plateaus, sums.windows(2).filter(|s| s[1] == s[0]).count());
+        }
+
+        #[test]
+        fn test_analyze_streams_like_sweep(depths in vec(any::<u64>(), 1..200), window in (1..10usize).prop_map(|w| NonZeroUsize::new(w).unwrap())) {
+            let data = depths.iter().map(u64::to_string).collect::<Vec<String>>().join("\n");
+
+            prop_assert_eq!(analyze(data.as_bytes(), window).ok(), Some(sweep(&depths, window)));
+        }
+    }
+}
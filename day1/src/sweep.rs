@@ -0,0 +1,188 @@
+use anyhow::{Context, Result};
+use std::{
+    cmp::Ordering,
+    collections::VecDeque,
+    fmt::{Display, Formatter},
+    io::BufRead,
+    num::NonZeroUsize,
+};
+
+/// How many depths too large for a `u16` are kept as examples in the report.
+const OVERSIZED_KEPT: usize = 8;
+
+/// Statistics of a sonar sweep, comparing the sums of consecutive windows of depths.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SweepReport {
+    pub window: usize,
+    pub depths: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    /// The most increases in a row.
+    pub longest_increasing_run: usize,
+    /// How many depths don't fit in a `u16`.
+    pub oversized: usize,
+    /// The first few of those depths, with their 1-based position among the depths.
+    pub oversized_examples: Vec<(usize, u64)>,
+}
+
+impl Display for SweepReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Depths: {}, windows of {}", self.depths, self.window)?;
+        writeln!(
+            f,
+            "Increases: {}, decreases: {}, plateaus: {}",
+            self.increases, self.decreases, self.plateaus
+        )?;
+        write!(f, "Longest increasing run: {}", self.longest_increasing_run)?;
+        if self.oversized > 0 {
+            let examples = self
+                .oversized_examples
+                .iter()
+                .map(|(position, depth)| format!("#{position}: {depth}"))
+                .collect::<Vec<String>>()
+                .join(", ");
+            write!(
+                f,
+                "\nDepths over {}: {} ({examples})",
+                u16::MAX,
+                self.oversized
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Compares the sums of consecutive windows of depths as they come in, remembering only the last
+/// window.
+pub struct Sweep {
+    recent: VecDeque<u64>,
+    run: usize,
+    report: SweepReport,
+}
+
+impl Sweep {
+    pub fn new(window: NonZeroUsize) -> Self {
+        Sweep {
+            recent: VecDeque::with_capacity(window.get() + 1),
+            run: 0,
+            report: SweepReport {
+                window: window.get(),
+                ..SweepReport::default()
+            },
+        }
+    }
+
+    pub fn push(&mut self, depth: u64) {
+        let report = &mut self.report;
+        report.depths += 1;
+        if depth > u16::MAX as u64 {
+            report.oversized += 1;
+            if report.oversized_examples.len() < OVERSIZED_KEPT {
+                report.oversized_examples.push((report.depths, depth));
+            }
+        }
+
+        self.recent.push_back(depth);
+        if self.recent.len() <= report.window {
+            return;
+        }
+
+        // consecutive windows share all but their outer depths, so only those decide
+        let dropped = self.recent.pop_front().unwrap_or_default();
+        match depth.cmp(&dropped) {
+            Ordering::Greater => {
+                report.increases += 1;
+                self.run += 1;
+                report.longest_increasing_run = report.longest_increasing_run.max(self.run);
+            }
+            Ordering::Less => {
+                report.decreases += 1;
+                self.run = 0;
+            }
+            Ordering::Equal => {
+                report.plateaus += 1;
+                self.run = 0;
+            }
+        }
+    }
+
+    pub fn report(&self) -> &SweepReport {
+        &self.report
+    }
+
+    pub fn finish(self) -> SweepReport {
+        self.report
+    }
+}
+
+/// Runs a sweep over depths that are already parsed.
+pub fn sweep(input: &[u64], window: NonZeroUsize) -> SweepReport {
+    let mut sweep = Sweep::new(window);
+    input.iter().for_each(|depth| sweep.push(*depth));
+
+    sweep.finish()
+}
+
+/// Runs a sweep over depths read line by line, skipping blank lines.
+pub fn analyze(reader: impl BufRead, window: NonZeroUsize) -> Result<SweepReport> {
+    let mut sweep = Sweep::new(window);
+    for (number, line) in reader.lines().enumerate() {
+        let line = line.context("Failed to read the depths")?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let depth = line
+            .parse::<u64>()
+            .with_context(|| format!("Line {} is not a depth: {line:?}", number + 1))?;
+        sweep.push(depth);
+    }
+
+    Ok(sweep.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(size: usize) -> NonZeroUsize {
+        NonZeroUsize::new(size).unwrap()
+    }
+
+    #[test]
+    fn test_analyze() -> Result<()> {
+        let data = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+        let report = analyze(data.as_bytes(), window(1))?;
+        assert_eq!(
+            (report.increases, report.decreases, report.plateaus),
+            (7, 2, 0)
+        );
+        assert_eq!(report.longest_increasing_run, 3);
+
+        let report = analyze(data.as_bytes(), window(3))?;
+        assert_eq!(
+            (report.increases, report.decreases, report.plateaus),
+            (5, 1, 1)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oversized() -> Result<()> {
+        let report = analyze("1\n70000\n\n3\n99999999999\n".as_bytes(), window(2))?;
+
+        assert_eq!(report.depths, 4);
+        assert_eq!(report.oversized, 2);
+        assert_eq!(
+            report.oversized_examples,
+            vec![(2, 70000), (4, 99999999999)]
+        );
+        assert!(analyze("1\nx\n".as_bytes(), window(1)).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+mod linter;
+
+pub use linter::{Brackets, Diagnostic, Lint, Problem, PUZZLE_PAIRS};
+
+use anyhow::{Context, Error, Result};
+use aoc_core::{
+    parse::{finish, one_of, IResult},
+    ParseError, Solution,
+};
+use nom::{
+    character::complete::line_ending,
+    combinator::map,
+    multi::{many1, separated_list1},
+    Parser,
+};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Vec<String>;
+    type Output1 = u64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn part_one(input: &[String]) -> u64 {
+    let brackets = Brackets::puzzle();
+
+    input
+        .iter()
+        .filter_map(
+            |line| match brackets.lint(line).first_corruption()?.problem {
+                Problem::Mismatched { found, .. } | Problem::Unopened { found } => Some(found),
+                Problem::Unclosed { .. } => None,
+            },
+        )
+        .map(|pair| [3, 57, 1197, 25137][pair])
+        .sum::<u64>()
+}
+
+fn part_two(input: &[String]) -> Result<i64> {
+    let brackets = Brackets::puzzle();
+    let mut results = input
+        .iter()
+        .enumerate()
+        .filter_map(|(row, line)| {
+            line_to_score(&brackets.lint(line))
+                .with_context(|| format!("Failed to score line {}", row + 1))
+                .transpose()
+        })
+        .collect::<Result<Vec<i64>>>()?;
+
+    results.sort_unstable();
+
+    results
+        .get(results.len() / 2)
+        .cloned()
+        .ok_or_else(|| Error::msg("Result didn't exist"))
+}
+
+/// The score of the completion of an incomplete line, `None` for a corrupted or complete one,
+/// failing when the score is too large.
+fn line_to_score(lint: &Lint) -> Result<Option<i64>> {
+    if lint.is_corrupted() || lint.completion.is_empty() {
+        return Ok(None);
+    }
+
+    let score = lint
+        .completion
+        .iter()
+        .try_fold(0i64, |acc, pair| {
+            acc.checked_mul(5)?.checked_add(*pair as i64 + 1)
+        })
+        .with_context(|| {
+            format!(
+                "The completion of {} closers scores more than {}",
+                lint.completion.len(),
+                i64::MAX
+            )
+        })?;
+
+    Ok(Some(score))
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<String>> {
+    separated_list1(line_ending, parse_line).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, String> {
+    map(many1(one_of("[]{}()<>")), |v: Vec<char>| {
+        v.into_iter().collect()
+    })
+    .parse(input)
+}
+
+fn read_input(data: impl AsRef<str>) -> Result<Vec<String>, ParseError> {
+    let data = data.as_ref();
+
+    finish(Day10::DAY, data, parse(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_one(&input);
+
+        assert_eq!(count, 26397);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one_many_lines() {
+        // past i32::MAX at 85,434 lines
+        let input = vec![">".to_string(); 100_000];
+
+        assert_eq!(part_one(&input), 2_513_700_000);
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_two(&input)?;
+
+        assert_eq!(count, 288957);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_too_deep() -> Result<()> {
+        let input = read_input(format!("{}\n<>", "<".repeat(27)))?;
+        assert_eq!(part_two(&input)?, 5i64.pow(27) - 1);
+
+        let input = read_input(format!("{}\n<>", "<".repeat(100)))?;
+        let error = part_two(&input).unwrap_err();
+        assert_eq!(error.to_string(), "Failed to score line 1");
+
+        Ok(())
+    }
+}
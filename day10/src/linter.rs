@@ -0,0 +1,291 @@
+use anyhow::{Error, Result};
+use std::{cmp::Reverse, ops::Range, str::FromStr};
+
+/// The bracket pairs of the puzzle, in the order of their scores.
+pub const PUZZLE_PAIRS: [(&str, &str); 4] = [("(", ")"), ("[", "]"), ("{", "}"), ("<", ">")];
+
+/// A set of bracket pairs, each delimiter one or more characters long. Pairs are referred to by
+/// their index in the set.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Brackets {
+    pairs: Vec<(String, String)>,
+    /// Every delimiter with its pair and whether it opens, the longest first.
+    delimiters: Vec<(String, usize, bool)>,
+}
+
+impl Brackets {
+    /// A set of pairs, failing for an empty delimiter or one used twice.
+    pub fn new<S: Into<String>>(pairs: impl IntoIterator<Item = (S, S)>) -> Result<Self> {
+        let pairs = pairs
+            .into_iter()
+            .map(|(open, close)| (open.into(), close.into()))
+            .collect::<Vec<(String, String)>>();
+        if pairs.is_empty() {
+            return Err(Error::msg("There should be at least one bracket pair"));
+        }
+
+        let mut delimiters = Vec::<(String, usize, bool)>::new();
+        for (pair, (open, close)) in pairs.iter().enumerate() {
+            for (delimiter, opens) in [(open, true), (close, false)] {
+                if delimiter.is_empty() {
+                    return Err(Error::msg("A delimiter can't be empty"));
+                }
+                if delimiters.iter().any(|(d, _, _)| d == delimiter) {
+                    return Err(Error::msg(format!("Delimiter {delimiter:?} is used twice")));
+                }
+                delimiters.push((delimiter.clone(), pair, opens));
+            }
+        }
+        delimiters.sort_by_key(|(delimiter, _, _)| Reverse(delimiter.len()));
+
+        Ok(Brackets { pairs, delimiters })
+    }
+
+    /// The brackets of the puzzle.
+    pub fn puzzle() -> Self {
+        Brackets::new(PUZZLE_PAIRS).expect("the puzzle pairs are valid")
+    }
+
+    pub fn pairs(&self) -> &[(String, String)] {
+        &self.pairs
+    }
+
+    pub fn open(&self, pair: usize) -> &str {
+        &self.pairs[pair].0
+    }
+
+    pub fn close(&self, pair: usize) -> &str {
+        &self.pairs[pair].1
+    }
+
+    /// Checks a line, reporting every problem and how to complete it.
+    ///
+    /// Delimiters are matched longest first and any other text is skipped. After a closer that
+    /// doesn't match the innermost open bracket, the brackets inside the one it does match are
+    /// taken as closed with it; without such a bracket the closer is ignored.
+    pub fn lint(&self, line: &str) -> Lint {
+        let mut diagnostics = Vec::new();
+        let mut open = Vec::<(Range<usize>, usize)>::new();
+
+        let (mut index, mut column) = (0, 0);
+        while index < line.len() {
+            let rest = &line[index..];
+            let Some((delimiter, pair, opens)) = self
+                .delimiters
+                .iter()
+                .find(|(delimiter, _, _)| rest.starts_with(delimiter.as_str()))
+            else {
+                let skipped = rest.chars().next().map_or(1, char::len_utf8);
+                index += skipped;
+                column += 1;
+                continue;
+            };
+            let span = column..column + delimiter.chars().count();
+            index += delimiter.len();
+            column = span.end;
+
+            if *opens {
+                open.push((span, *pair));
+                continue;
+            }
+            match open.last() {
+                None => diagnostics.push(Diagnostic {
+                    span,
+                    problem: Problem::Unopened { found: *pair },
+                }),
+                Some((_, expected)) if expected == pair => {
+                    open.pop();
+                }
+                Some((_, expected)) => {
+                    diagnostics.push(Diagnostic {
+                        span,
+                        problem: Problem::Mismatched {
+                            expected: *expected,
+                            found: *pair,
+                        },
+                    });
+                    if let Some(matching) = open.iter().rposition(|(_, p)| p == pair) {
+                        open.truncate(matching);
+                    }
+                }
+            }
+        }
+
+        let completion = open.iter().rev().map(|(_, pair)| *pair).collect();
+        diagnostics.extend(open.into_iter().map(|(span, pair)| Diagnostic {
+            span,
+            problem: Problem::Unclosed { pair },
+        }));
+        diagnostics.sort_by_key(|diagnostic| diagnostic.span.start);
+
+        Lint {
+            diagnostics,
+            completion,
+        }
+    }
+
+    /// The closers completing a line, innermost first.
+    pub fn completion(&self, lint: &Lint) -> String {
+        lint.completion
+            .iter()
+            .map(|pair| self.close(*pair))
+            .collect()
+    }
+}
+
+impl Default for Brackets {
+    fn default() -> Self {
+        Brackets::puzzle()
+    }
+}
+
+impl FromStr for Brackets {
+    type Err = Error;
+
+    /// Parses whitespace separated pairs with `..` between the delimiters, such as
+    /// `(..) [..] begin..end`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pairs = s
+            .split_whitespace()
+            .map(|pair| {
+                pair.split_once("..").ok_or_else(|| {
+                    Error::msg(format!("Expected a pair such as begin..end, got {pair:?}"))
+                })
+            })
+            .collect::<Result<Vec<(&str, &str)>>>()?;
+
+        Brackets::new(pairs)
+    }
+}
+
+/// Something wrong with the brackets of a line, referring to pairs by their index.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Problem {
+    /// A closer other than the one of the innermost open bracket.
+    Mismatched { expected: usize, found: usize },
+    /// A closer without any open bracket.
+    Unopened { found: usize },
+    /// A bracket still open at the end of the line.
+    Unclosed { pair: usize },
+}
+
+/// A problem with the 0-based columns of the delimiter it is about.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    pub span: Range<usize>,
+    pub problem: Problem,
+}
+
+impl Diagnostic {
+    /// Whether the problem is a wrong closer rather than a missing one.
+    pub fn is_corruption(&self) -> bool {
+        !matches!(self.problem, Problem::Unclosed { .. })
+    }
+
+    pub fn message(&self, brackets: &Brackets) -> String {
+        match self.problem {
+            Problem::Mismatched { expected, found } => format!(
+                "expected `{}`, found `{}`",
+                brackets.close(expected),
+                brackets.close(found)
+            ),
+            Problem::Unopened { found } => {
+                format!(
+                    "unexpected `{}` without an open bracket",
+                    brackets.close(found)
+                )
+            }
+            Problem::Unclosed { pair } => format!("`{}` is never closed", brackets.open(pair)),
+        }
+    }
+}
+
+/// Everything wrong with a line, ordered by column, and the pairs left to close to complete it,
+/// innermost first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Lint {
+    pub diagnostics: Vec<Diagnostic>,
+    pub completion: Vec<usize>,
+}
+
+impl Lint {
+    /// The first wrong closer, which makes the line corrupted.
+    pub fn first_corruption(&self) -> Option<&Diagnostic> {
+        self.diagnostics.iter().find(|d| d.is_corruption())
+    }
+
+    pub fn is_corrupted(&self) -> bool {
+        self.first_corruption().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recovery() {
+        let brackets = Brackets::puzzle();
+
+        let lint = brackets.lint("{([(<{}[<>[]}>{[]{[(<()>");
+        assert_eq!(
+            lint.first_corruption(),
+            Some(&Diagnostic {
+                span: 12..13,
+                problem: Problem::Mismatched {
+                    expected: 1,
+                    found: 2
+                },
+            })
+        );
+
+        // the ] closes the [ after the stray ) is skipped, and the > closes the < past the (
+        let lint = brackets.lint("[)]<(>}");
+        let messages = lint
+            .diagnostics
+            .iter()
+            .map(|d| (d.span.start, d.message(&brackets)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                (1, "expected `]`, found `)`".to_string()),
+                (5, "expected `)`, found `>`".to_string()),
+                (6, "unexpected `}` without an open bracket".to_string()),
+            ]
+        );
+        assert!(lint.completion.is_empty());
+
+        let lint = brackets.lint("[({(<(())[]>[[{[]{<()<>>");
+        assert!(!lint.is_corrupted());
+        assert_eq!(brackets.completion(&lint), "}}]])})]");
+        assert_eq!(
+            lint.diagnostics[0].message(&brackets),
+            "`[` is never closed"
+        );
+    }
+
+    #[test]
+    fn test_multi_character_delimiters() -> Result<()> {
+        let brackets = "begin..end (..) <!--..--> <..>".parse::<Brackets>()?;
+
+        let lint = brackets.lint("begin (x) <!-- <a> end");
+
+        // the end closes the comment left open along with the begin
+        assert_eq!(
+            lint.diagnostics,
+            [Diagnostic {
+                span: 19..22,
+                problem: Problem::Mismatched {
+                    expected: 2,
+                    found: 0
+                },
+            },]
+        );
+        assert_eq!(brackets.completion(&lint), "");
+        assert!("( )".parse::<Brackets>().is_err());
+        assert!("(..) (..]".parse::<Brackets>().is_err());
+
+        Ok(())
+    }
+}
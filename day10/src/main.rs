@@ -1,175 +1,33 @@
-use anyhow::{Error, Result};
-use nom::{
-    character::{complete::line_ending, complete::one_of},
-    combinator::map,
-    multi::{many1, separated_list1},
-    IResult, Parser,
-};
+use anyhow::Result;
+use aoc_core::args::Command;
+use day10::{Brackets, Day10};
 
-const DATA: &str = include_str!("input.txt");
+const USAGE: &str = "Usage: day10 --lint [--pairs \"(..) [..] begin..end\"] [PATH]";
 
+/// Solves the puzzle, or with `--lint` reports every bracket problem of the lines of PATH (stdin
+/// when left out or `-`) as LINE:COLUMN, with the closers completing the line, for the brackets
+/// of the puzzle or the given pairs.
 fn main() -> Result<()> {
-    let input = read_input(DATA)?;
-
-    let (took, result) = took::took(|| part_one(&input));
-    println!("Result part one: {result}");
-    println!("Time spent: {took}");
-
-    let (took, result) = took::took(|| part_two(&input));
-    println!("Result part two: {}", result?);
-    println!("Time spent: {took}");
-
-    Ok(())
-}
-
-fn part_one(input: &[Vec<Token>]) -> i32 {
-    input
-        .iter()
-        .filter_map(|s| check_line(s))
-        .map(|c| c.points1())
-        .sum::<i32>()
-}
-
-fn part_two(input: &[Vec<Token>]) -> Result<i64> {
-    let mut results = input
-        .iter()
-        .filter_map(|s| line_to_score(s))
-        .collect::<Vec<i64>>();
-
-    results.sort_unstable();
-
-    results
-        .get(results.len() / 2)
-        .cloned()
-        .ok_or_else(|| Error::msg("Result didn't exist"))
-}
-
-fn check_line(line: &[Token]) -> Option<Token> {
-    let mut stack: Vec<Token> = Vec::new();
-    get_failing_token(line, &mut stack)
-}
-
-fn line_to_score(line: &[Token]) -> Option<i64> {
-    let mut stack: Vec<Token> = Vec::new();
-    if get_failing_token(line, &mut stack).is_some() {
-        return None;
-    };
-
-    let score = stack
-        .into_iter()
-        .map(|c| c.opposite())
-        .map(|c| c.points2())
-        .rfold(0, |acc, c| acc * 5 + c);
-
-    Some(score)
-}
-
-fn get_failing_token(line: &[Token], stack: &mut Vec<Token>) -> Option<Token> {
-    for t in line {
-        match t {
-            t if t.is_open() => stack.push(*t),
-            _ => {
-                let pop = stack.remove(stack.len() - 1);
-                if t != &pop.opposite() {
-                    return Some(*t);
-                }
-            }
-        }
+    match Command::from_env("--lint", USAGE) {
+        Some(command) => lint(command),
+        None => aoc_core::run::<Day10>(),
     }
-
-    None
 }
 
-#[derive(Clone, Copy, PartialEq)]
-struct Token(char);
+fn lint(mut command: Command) -> Result<()> {
+    let brackets: Brackets = command.option("--pairs")?.unwrap_or_default();
+    let source = command.source()?;
 
-impl Token {
-    pub fn new(c: char) -> Self {
-        match c {
-            '(' | ')' | '[' | ']' | '{' | '}' | '<' | '>' => Token(c),
-            _ => panic!("Character '{c}' is not supported"),
+    for (row, line) in source.read()?.lines().enumerate() {
+        let lint = brackets.lint(line);
+        for diagnostic in &lint.diagnostics {
+            let column = diagnostic.span.start + 1;
+            println!("{}:{column}: {}", row + 1, diagnostic.message(&brackets));
         }
-    }
-
-    pub fn opposite(&self) -> Self {
-        match self.0 {
-            '(' => Token(')'),
-            ')' => Token('('),
-            '[' => Token(']'),
-            ']' => Token('['),
-            '{' => Token('}'),
-            '}' => Token('{'),
-            '<' => Token('>'),
-            '>' => Token('<'),
-            _ => unreachable!(),
+        if !lint.completion.is_empty() {
+            println!("{}: complete with {}", row + 1, brackets.completion(&lint));
         }
     }
 
-    pub fn is_open(&self) -> bool {
-        ['(', '[', '{', '<'].contains(&self.0)
-    }
-
-    pub fn points1(&self) -> i32 {
-        match self.0 {
-            ')' => 3,
-            ']' => 57,
-            '}' => 1197,
-            '>' => 25137,
-            _ => unreachable!(),
-        }
-    }
-
-    pub fn points2(&self) -> i64 {
-        match self.0 {
-            ')' => 1,
-            ']' => 2,
-            '}' => 3,
-            '>' => 4,
-            _ => unreachable!(),
-        }
-    }
-}
-
-fn parse(input: &str) -> IResult<&str, Vec<Vec<Token>>> {
-    separated_list1(line_ending, parse_line).parse(input)
-}
-
-fn parse_line(input: &str) -> IResult<&str, Vec<Token>> {
-    map(many1(one_of("[]{}()<>")), |v: Vec<char>| {
-        v.iter().map(|c| Token::new(*c)).collect()
-    })
-    .parse(input)
-}
-
-fn read_input(data: &str) -> Result<Vec<Vec<Token>>> {
-    let (_, input) = parse(data).expect("Parse failure");
-
-    Ok(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() -> Result<()> {
-        let input = read_input(DATA)?;
-
-        let count = part_one(&input);
-
-        assert_eq!(count, 339477);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_part_two() -> Result<()> {
-        let mut input = read_input(DATA)?;
-
-        let count = part_two(&mut input)?;
-
-        assert_eq!(count, 3049320156);
-
-        Ok(())
-    }
+    Ok(())
 }
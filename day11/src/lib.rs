@@ -0,0 +1,113 @@
+use anyhow::Result;
+use aoc_core::{parse::finish, ParseError, Solution};
+use grid::{parse::digits, Grid};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Grid<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        Ok(part_two(input))
+    }
+}
+
+fn part_one(grid: &Grid<u8>) -> usize {
+    let mut grid = grid.clone();
+
+    (0..100).map(|_| step(&mut grid)).sum::<usize>()
+}
+
+fn part_two(grid: &Grid<u8>) -> usize {
+    let mut grid = grid.clone();
+
+    let octopuses = grid.len();
+
+    (1..).find(|_| step(&mut grid) == octopuses).unwrap()
+}
+
+fn step(grid: &mut Grid<u8>) -> usize {
+    grid.values_mut().for_each(|energy| *energy += 1);
+
+    let mut flashed = grid.map(|_| false);
+    let mut to_flash = grid
+        .iter()
+        .filter(|(_, energy)| **energy > 9)
+        .map(|(point, _)| point)
+        .collect::<Vec<_>>();
+
+    while let Some(point) = to_flash.pop() {
+        if flashed[point] {
+            continue;
+        }
+        flashed[point] = true;
+
+        for neighbour in grid.neighbours8(point) {
+            grid[neighbour] += 1;
+            if grid[neighbour] > 9 && !flashed[neighbour] {
+                to_flash.push(neighbour);
+            }
+        }
+    }
+
+    grid.values_mut()
+        .filter(|energy| **energy > 9)
+        .for_each(|energy| *energy = 0);
+
+    flashed.values().filter(|flashed| **flashed).count()
+}
+
+fn read_input(data: impl AsRef<str>) -> Result<Grid<u8>, ParseError> {
+    let data = data.as_ref();
+
+    finish(Day11::DAY, data, digits(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_one(&input);
+
+        assert_eq!(count, 1656);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_two(&input);
+
+        assert_eq!(count, 195);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+use anyhow::{Error, Result};
+use aoc_core::{
+    parse::{finish, IResult},
+    ParseError, Solution,
+};
+use graph::{count_paths, Graph};
+use nom::{
+    character::complete::{self, alpha1, line_ending},
+    combinator::map,
+    multi::separated_list1,
+    sequence::separated_pair,
+    Parser,
+};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Caves;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn part_one(caves: &Caves) -> Result<usize> {
+    count_walks(caves, false)
+}
+
+fn part_two(caves: &Caves) -> Result<usize> {
+    count_walks(caves, true)
+}
+
+fn count_walks(caves: &Caves, revisit: bool) -> Result<usize> {
+    let cave = |name| {
+        caves
+            .index(name)
+            .ok_or_else(|| Error::msg(format!("There is no {name} cave")))
+    };
+    if caves.small.iter().filter(|small| small.is_some()).count() > u64::BITS as usize {
+        return Err(Error::msg("Too many small caves to remember"));
+    }
+    if let Some((from, to)) = caves.big_tunnel() {
+        return Err(Error::msg(format!(
+            "Big caves {from} and {to} are connected, so walks could go round them forever"
+        )));
+    }
+
+    let walks = Walks {
+        caves,
+        start: cave("start")?,
+        end: cave("end")?,
+        revisit,
+    };
+    let start = Walk {
+        cave: walks.start,
+        visited: 0,
+        revisited: false,
+    };
+
+    Ok(count_paths(&walks, start, |walk| walk.cave == walks.end))
+}
+
+/// The map of caves, numbered in order of appearance.
+pub struct Caves {
+    names: Vec<String>,
+    /// The bit of every small cave in [`Walk::visited`], `None` for big caves.
+    small: Vec<Option<u64>>,
+    tunnels: Vec<Vec<usize>>,
+}
+
+impl Caves {
+    pub fn new(connections: Vec<(&str, &str)>) -> Self {
+        let mut caves = Caves {
+            names: Vec::new(),
+            small: Vec::new(),
+            tunnels: Vec::new(),
+        };
+        for (from, to) in connections {
+            let (from, to) = (caves.add(from), caves.add(to));
+            caves.tunnels[from].push(to);
+            caves.tunnels[to].push(from);
+        }
+
+        caves
+    }
+
+    fn add(&mut self, name: &str) -> usize {
+        if let Some(index) = self.index(name) {
+            return index;
+        }
+
+        let small = name.chars().all(|c| c.is_lowercase()).then(|| {
+            let count = self.small.iter().flatten().count() as u32;
+            1u64.checked_shl(count).unwrap_or(0)
+        });
+        self.names.push(name.to_string());
+        self.small.push(small);
+        self.tunnels.push(Vec::new());
+
+        self.names.len() - 1
+    }
+
+    /// A tunnel between two big caves, if there is one.
+    fn big_tunnel(&self) -> Option<(&str, &str)> {
+        self.tunnels.iter().enumerate().find_map(|(from, tunnels)| {
+            let to = tunnels
+                .iter()
+                .find(|to| self.small[from].is_none() && self.small[**to].is_none())?;
+            Some((self.names[from].as_str(), self.names[*to].as_str()))
+        })
+    }
+
+    fn index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+}
+
+/// Where a walk through the caves is, the small caves it went through and whether it already
+/// went through one of them twice.
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct Walk {
+    cave: usize,
+    visited: u64,
+    revisited: bool,
+}
+
+/// The walks through the caves that never return to the start, and go through small caves only
+/// once, or one of them twice when `revisit` is set.
+///
+/// A walk only ever adds to the small caves it went through, and [`count_walks`] rejects maps
+/// with two big caves connected, so the walks can't go round in circles.
+struct Walks<'a> {
+    caves: &'a Caves,
+    start: usize,
+    end: usize,
+    revisit: bool,
+}
+
+impl Graph for Walks<'_> {
+    type Node = Walk;
+
+    fn neighbours(&self, walk: &Walk) -> impl Iterator<Item = Walk> {
+        self.caves.tunnels[walk.cave]
+            .iter()
+            .filter(|cave| **cave != self.start)
+            .filter_map(|&cave| match self.caves.small[cave] {
+                None => Some(Walk {
+                    cave,
+                    ..walk.clone()
+                }),
+                Some(bit) if walk.visited & bit == 0 => Some(Walk {
+                    cave,
+                    visited: walk.visited | bit,
+                    revisited: walk.revisited,
+                }),
+                Some(_) if self.revisit && !walk.revisited => Some(Walk {
+                    cave,
+                    visited: walk.visited,
+                    revisited: true,
+                }),
+                Some(_) => None,
+            })
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Caves> {
+    map(separated_list1(line_ending, parse_line), Caves::new).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, (&str, &str)> {
+    separated_pair(alpha1, complete::char('-'), alpha1).parse(input)
+}
+
+fn read_input(data: impl AsRef<str>) -> Result<Caves, ParseError> {
+    let data = data.as_ref();
+
+    finish(Day12::DAY, data, parse(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_one(&input)?;
+
+        assert_eq!(count, 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_two(&input)?;
+
+        assert_eq!(count, 36);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_big_caves_connected() -> Result<()> {
+        let input = read_input("start-A\nA-B\nB-end")?;
+
+        let error = part_one(&input).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Big caves A and B are connected, so walks could go round them forever"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{finish, one_of, tag, IResult},
+    ParseError, Solution,
+};
+use grid::{Position, SparseGrid};
+use nom::{
+    character::complete::{self, line_ending},
+    combinator::map,
+    multi::{count, separated_list1},
+    sequence::{preceded, separated_pair},
+    Parser,
+};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = (SparseGrid, Vec<Instruction>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        let (grid, instructions) = input;
+
+        Ok(part_one(grid, instructions))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        let (grid, instructions) = input;
+
+        Ok(part_two(grid, instructions))
+    }
+}
+
+fn part_one(grid: &SparseGrid, instructions: &[Instruction]) -> usize {
+    let grid = fold(grid.clone(), instructions.first().unwrap());
+
+    grid.len()
+}
+
+fn part_two(grid: &SparseGrid, instructions: &[Instruction]) -> usize {
+    let grid = instructions.iter().fold(grid.clone(), fold);
+
+    grid.len()
+}
+
+/// The dots after every fold, which draw the letters answering part two.
+pub fn draw((grid, instructions): &(SparseGrid, Vec<Instruction>)) -> String {
+    let grid = instructions.iter().fold(grid.clone(), fold);
+
+    grid.render(|dot| if dot.is_some() { '#' } else { ' ' })
+}
+
+fn fold(grid: SparseGrid, instruction: &Instruction) -> SparseGrid {
+    grid.map_positions(|position| instruction.fold(position))
+}
+
+#[derive(Clone, Debug)]
+enum Direction {
+    X,
+    Y,
+}
+
+#[derive(Clone, Debug)]
+pub struct Instruction {
+    direction: Direction,
+    location: isize,
+}
+
+impl Instruction {
+    pub fn fold(&self, (mut x, mut y): Position) -> Position {
+        match self.direction {
+            Direction::X => {
+                if x > self.location {
+                    x = self.location - (x - self.location)
+                }
+            }
+            Direction::Y => {
+                if y > self.location {
+                    y = self.location - (y - self.location)
+                }
+            }
+        }
+        (x, y)
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, (SparseGrid, Vec<Instruction>)> {
+    separated_pair(parse_dots, count(line_ending, 2), parse_instructions).parse(input)
+}
+
+fn parse_dots(input: &str) -> IResult<&str, SparseGrid> {
+    map(separated_list1(line_ending, parse_dot_line), |dots| {
+        dots.into_iter().collect()
+    })
+    .parse(input)
+}
+
+fn parse_dot_line(input: &str) -> IResult<&str, Position> {
+    separated_pair(parse_coordinate, complete::char(','), parse_coordinate).parse(input)
+}
+
+fn parse_coordinate(input: &str) -> IResult<&str, isize> {
+    map(complete::i32, |c| c as isize).parse(input)
+}
+
+fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
+    separated_list1(line_ending, parse_instruction_line).parse(input)
+}
+
+fn parse_instruction_line(input: &str) -> IResult<&str, Instruction> {
+    map(
+        preceded(
+            tag("fold along "),
+            separated_pair(parse_direction, complete::char('='), parse_coordinate),
+        ),
+        |(direction, location)| Instruction {
+            direction,
+            location,
+        },
+    )
+    .parse(input)
+}
+
+fn parse_direction(input: &str) -> IResult<&str, Direction> {
+    map(one_of("xy"), |a| match a {
+        'x' => Direction::X,
+        _ => Direction::Y,
+    })
+    .parse(input)
+}
+
+fn read_input(data: impl AsRef<str>) -> Result<(SparseGrid, Vec<Instruction>), ParseError> {
+    let data = data.as_ref();
+
+    finish(Day13::DAY, data, parse(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_one(&input.0, &input.1);
+
+        assert_eq!(count, 17);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_two(&input.0, &input.1);
+
+        assert_eq!(count, 16);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_draw_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let drawing = draw(&input);
+
+        assert_eq!(drawing, "#####\n#   #\n#   #\n#   #\n#####\n");
+
+        Ok(())
+    }
+}
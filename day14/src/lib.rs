@@ -0,0 +1,156 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{finish, tag, IResult},
+    ParseError, Solution,
+};
+use nom::{
+    character::complete::{alpha1, line_ending, satisfy},
+    combinator::map,
+    multi::{count, separated_list1},
+    sequence::separated_pair,
+    Parser,
+};
+use std::collections::HashMap;
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Input;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        Ok(part_two(input))
+    }
+}
+
+fn part_one(input: &Input) -> i64 {
+    execute(input, 10)
+}
+
+fn part_two(input: &Input) -> i64 {
+    execute(input, 40)
+}
+
+fn execute(input: &Input, amount: usize) -> i64 {
+    let combinations = determine_possible_combinations(&input.rules);
+    let initialized_input = initialize_input(&input.template);
+    let result = (0..amount).fold(initialized_input, |s, _| step(&s, &combinations));
+
+    let (least, most) = least_most(&input.template, &result);
+
+    most - least
+}
+
+fn determine_possible_combinations(
+    rules: &HashMap<(char, char), char>,
+) -> HashMap<(char, char), Vec<(char, char)>> {
+    rules
+        .iter()
+        .map(|(k, v)| (*k, vec![(k.0, *v), (*v, k.1)]))
+        .collect::<HashMap<(char, char), Vec<(char, char)>>>()
+}
+
+fn initialize_input(template: &str) -> HashMap<(char, char), i64> {
+    let mut input: HashMap<(char, char), i64> = HashMap::new();
+    template
+        .chars()
+        .collect::<Vec<char>>()
+        .windows(2)
+        .map(|a| (*a.first().unwrap(), *a.get(1).unwrap()))
+        .for_each(|c| {
+            *input.entry(c).or_insert(0) += 1;
+        });
+    input
+}
+
+fn step(
+    input: &HashMap<(char, char), i64>,
+    combinations: &HashMap<(char, char), Vec<(char, char)>>,
+) -> HashMap<(char, char), i64> {
+    let mut map = HashMap::new();
+    input
+        .iter()
+        .filter(|(_, value)| value > &&0)
+        .for_each(|(key, value)| {
+            combinations.get(key).unwrap().iter().for_each(|c| {
+                *map.entry(*c).or_insert(0) += value;
+            })
+        });
+
+    map
+}
+
+fn least_most(template: &str, input: &HashMap<(char, char), i64>) -> (i64, i64) {
+    let mut map: HashMap<char, i64> = HashMap::new();
+    // count the first char of the template
+    map.insert(*template.chars().collect::<Vec<char>>().first().unwrap(), 1);
+
+    // count right-hand chars
+    input
+        .iter()
+        .for_each(|((_, b), c)| *map.entry(*b).or_insert(0) += c);
+
+    // sort the map values and return the least and most
+    let mut result = map.values().copied().collect::<Vec<i64>>();
+    result.sort_unstable();
+
+    (*result.first().unwrap(), *result.last().unwrap())
+}
+
+pub struct Input {
+    template: String,
+    rules: HashMap<(char, char), char>,
+}
+
+fn parse(input: &str) -> IResult<&str, Input> {
+    map(
+        separated_pair(parse_template, count(line_ending, 2), parse_insertion_rules),
+        |(template, rules)| Input { template, rules },
+    )
+    .parse(input)
+}
+
+fn parse_template(input: &str) -> IResult<&str, String> {
+    map(alpha1, |s: &str| s.to_string()).parse(input)
+}
+
+fn parse_insertion_rules(input: &str) -> IResult<&str, HashMap<(char, char), char>> {
+    map(
+        separated_list1(line_ending, parse_insertion_rule),
+        |rules| rules.into_iter().collect::<HashMap<(char, char), char>>(),
+    )
+    .parse(input)
+}
+
+fn parse_insertion_rule(input: &str) -> IResult<&str, ((char, char), char)> {
+    separated_pair((parse_element, parse_element), tag(" -> "), parse_element).parse(input)
+}
+
+fn parse_element(input: &str) -> IResult<&str, char> {
+    satisfy(|c| c.is_ascii_uppercase()).parse(input)
+}
+
+fn read_input(data: impl AsRef<str>) -> Result<Input, ParseError> {
+    let data = data.as_ref();
+
+    finish(Day14::DAY, data, parse(data))
+}
@@ -0,0 +1,93 @@
+use anyhow::Result;
+use aoc_core::{parse::finish, ParseError, Solution};
+use graph::{astar, manhattan, Cells};
+use grid::{parse::digits, Grid, ORTHOGONAL};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Grid<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        Ok(part_two(input))
+    }
+}
+
+fn part_one(grid: &Grid<u8>) -> usize {
+    traverse(grid)
+}
+
+fn part_two(grid: &Grid<u8>) -> usize {
+    let grid = multiply_by_5(grid);
+
+    traverse(&grid)
+}
+
+/// The lowest total risk of the cells entered on the way from the top left to the bottom right.
+fn traverse(grid: &Grid<u8>) -> usize {
+    let start = (0, 0);
+    let goal = (grid.width() - 1, grid.height() - 1);
+    let risk = Cells::new(grid, &ORTHOGONAL, |_, to| Some(*to as usize));
+
+    let (_, cost) = astar(&risk, start, |p| *p == goal, manhattan(goal)).unwrap();
+
+    cost
+}
+
+/// Tiles the grid five times in both directions, adding one to the risk of every tile to the right
+/// or below and wrapping from 9 back to 1.
+fn multiply_by_5(grid: &Grid<u8>) -> Grid<u8> {
+    let (width, height) = (grid.width(), grid.height());
+
+    Grid::from_fn(width * 5, height * 5, |(x, y)| {
+        let risk = grid[(x % width, y % height)] as usize + x / width + y / height;
+        ((risk - 1) % 9 + 1) as u8
+    })
+}
+
+fn parse_input(input: impl AsRef<str>) -> Result<Grid<u8>, ParseError> {
+    let input = input.as_ref();
+
+    finish(Day15::DAY, input, digits(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse_input(TESTDATA)?), 40);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse_input(TESTDATA)?), 315);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,400 @@
+use anyhow::{Context, Error, Result};
+use aoc_core::{
+    parse::{self as nom_parse, finish, tag, IResult},
+    ParseError, Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::{take, take_while1},
+    character::complete,
+    combinator::{all_consuming, map, map_res},
+    error::{ErrorKind, FromExternalError},
+    multi::{count, many1, many_till},
+    sequence::{pair, preceded},
+    AsChar, Parser,
+};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Packet;
+    type Output1 = u16;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input.as_bytes())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        Ok(part_two(input))
+    }
+}
+
+fn part_one(input: &Packet) -> u16 {
+    input
+        .flatten()
+        .iter()
+        .map(|p| p.version as u16)
+        .sum::<u16>()
+}
+
+fn part_two(input: &Packet) -> u64 {
+    input.content
+}
+
+#[derive(Debug)]
+pub struct Packet {
+    version: u8,
+    content: u64,
+    subpackets: Vec<Packet>,
+}
+
+impl Packet {
+    pub fn from_content(version: u8, content: u64) -> Self {
+        Packet {
+            version,
+            content,
+            subpackets: Vec::new(),
+        }
+    }
+
+    /// An operator packet, failing when it has too few or too many subpackets for its type or
+    /// its value doesn't fit in a `u64`.
+    pub fn from_subpackets(version: u8, type_id: u8, subpackets: Vec<Packet>) -> Result<Self> {
+        if subpackets.is_empty() {
+            return Err(Error::msg("An operator needs at least one subpacket"));
+        }
+        let mut values = subpackets.iter().map(|s| s.content);
+        let content = match (type_id, subpackets.as_slice()) {
+            (0, _) => values
+                .try_fold(0u64, u64::checked_add)
+                .context("The sum doesn't fit in a u64")?,
+            (1, _) => values
+                .try_fold(1u64, u64::checked_mul)
+                .context("The product doesn't fit in a u64")?,
+            (2, _) => values.min().unwrap_or_default(),
+            (3, _) => values.max().unwrap_or_default(),
+            (5, [first, second]) => u64::from(first.content > second.content),
+            (6, [first, second]) => u64::from(first.content < second.content),
+            (7, [first, second]) => u64::from(first.content == second.content),
+            (5..=7, _) => {
+                return Err(Error::msg(format!(
+                    "A comparison needs exactly two subpackets, not {}",
+                    subpackets.len()
+                )));
+            }
+            _ => 0,
+        };
+
+        Ok(Packet {
+            version,
+            content,
+            subpackets,
+        })
+    }
+
+    pub fn flatten(&self) -> Vec<&Packet> {
+        let vec = vec![self];
+        let flattened_subpackets = self
+            .subpackets
+            .iter()
+            .flat_map(|s| s.flatten())
+            .collect::<Vec<&Packet>>();
+        [vec, flattened_subpackets].concat()
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Packet> {
+    alt((parse_packet_4, parse_packet_not_4)).parse(input)
+}
+
+fn parse_packet_4(input: &str) -> IResult<&str, Packet> {
+    let (input, version) = parse_version(input)?;
+    let (input, content) = preceded(tag("100"), parse_literal).parse(input)?;
+
+    let packet = Packet::from_content(version, content);
+
+    Ok((input, packet))
+}
+
+fn parse_packet_not_4(input: &str) -> IResult<&str, Packet> {
+    let start = input;
+    let (input, version) = parse_version(input)?;
+    let (input, type_id) = parse_type_id(input)?;
+    let (input, length_type_id) = take(1usize)(input)?;
+    let (input, subpackets) = if length_type_id == "0" {
+        let (input, total_subpacket_length) =
+            map_res(take(15usize), |tsl| usize::from_str_radix(tsl, 2)).parse(input)?;
+        let (input, sub_input) = take(total_subpacket_length)(input)?;
+        // the length covers the subpackets exactly, leaving no bits for padding
+        let (_, subpackets) = all_consuming(many1(parse)).parse(sub_input)?;
+        (input, subpackets)
+    } else {
+        let (input, number_of_subpackets) =
+            map_res(take(11usize), |tsl| usize::from_str_radix(tsl, 2)).parse(input)?;
+        count(parse, number_of_subpackets).parse(input)?
+    };
+
+    // a well-formed packet with the wrong operands can't be anything else, so don't backtrack
+    let packet = Packet::from_subpackets(version, type_id, subpackets).map_err(|e| {
+        nom::Err::Failure(nom_parse::Error::from_external_error(
+            start,
+            ErrorKind::MapRes,
+            e,
+        ))
+    })?;
+
+    Ok((input, packet))
+}
+
+fn parse_version(input: &str) -> IResult<&str, u8> {
+    parse_three_bits(input)
+}
+
+fn parse_type_id(input: &str) -> IResult<&str, u8> {
+    parse_three_bits(input)
+}
+
+fn parse_three_bits(input: &str) -> IResult<&str, u8> {
+    map_res(take(3usize), |c: &str| u8::from_str_radix(c, 2)).parse(input)
+}
+
+fn parse_literal(input: &str) -> IResult<&str, u64> {
+    map_res(
+        many_till(parse_literal_part, parse_literal_end),
+        |(v, s)| {
+            let mut buf = v.join("");
+            buf.push_str(s);
+            u64::from_str_radix(&buf, 2)
+        },
+    )
+    .parse(input)
+}
+
+fn parse_literal_part(input: &str) -> IResult<&str, &str> {
+    map(pair(complete::char('1'), take(4usize)), |(_, b)| b).parse(input)
+}
+
+fn parse_literal_end(input: &str) -> IResult<&str, &str> {
+    map(pair(complete::char('0'), take(4usize)), |(_, b)| b).parse(input)
+}
+
+fn parse_hex_as_binary(input: &[u8]) -> IResult<&[u8], String> {
+    map_res(parse_arr_as_hex, |s: &[u8]| {
+        s.iter()
+            .map(|c| {
+                u8::from_str_radix(&c.as_char().to_string(), 16).map(|val| format!("{:04b}", val))
+            })
+            .collect::<Result<Vec<String>, _>>()
+            .map(|v| v.join(""))
+    })
+    .parse(input)
+}
+
+fn parse_arr_as_hex(input: &[u8]) -> IResult<&[u8], &[u8]> {
+    take_while1(|c: u8| c.is_hex_digit())(input)
+}
+
+fn read_input(data: impl AsRef<[u8]>) -> Result<Packet, ParseError> {
+    let data = data.as_ref();
+    let binary = finish(Day16::DAY, data, parse_hex_as_binary(data))?;
+
+    // failures are located at the hex digit holding the offending bit
+    let locate = |rest: &str| (rest.as_ptr() as usize - binary.as_ptr() as usize) / 4;
+    match parse(binary.as_str()) {
+        Ok((rest, packet)) if rest.bytes().all(|b| b == b'0') => Ok(packet),
+        Ok((rest, _)) => Err(ParseError::at(
+            Day16::DAY,
+            data,
+            locate(rest),
+            "zero padding",
+        )),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::at(
+            Day16::DAY,
+            data,
+            locate(e.input),
+            e.describe(),
+        )),
+        Err(nom::Err::Incomplete(_)) => {
+            Err(ParseError::at(Day16::DAY, data, data.len(), "more input"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+
+    #[test]
+    fn test_malformed_operators() {
+        let expected = |data: &str| read_input(data).map_err(|e| e.expected).unwrap_err();
+
+        // a minimum of no subpackets, a greater than of one and a sum past u64::MAX
+        assert!(expected("0A000").contains("at least one subpacket"));
+        assert!(expected("16004408").contains("exactly two subpackets, not 1"));
+        assert!(expected("020084FFFFFFFFFFFFFFFFFFEF102").contains("doesn't fit in a u64"));
+        // a sum of a literal and bits too few for another packet
+        assert_eq!(expected("00003C4080"), "end of input");
+    }
+
+    /// A packet before encoding, evaluated without the parser as a reference.
+    #[derive(Clone, Debug)]
+    enum Tree {
+        Literal {
+            version: u8,
+            value: u64,
+        },
+        Operator {
+            version: u8,
+            type_id: u8,
+            by_count: bool,
+            children: Vec<Tree>,
+        },
+    }
+
+    impl Tree {
+        fn version_sum(&self) -> u16 {
+            match self {
+                Tree::Literal { version, .. } => *version as u16,
+                Tree::Operator {
+                    version, children, ..
+                } => *version as u16 + children.iter().map(Tree::version_sum).sum::<u16>(),
+            }
+        }
+
+        /// The value of the packet, `None` when it is malformed: an operator without children, a
+        /// comparison of other than two, or a value that overflows a `u64`.
+        fn eval(&self) -> Option<u64> {
+            let (type_id, children) = match self {
+                Tree::Literal { value, .. } => return Some(*value),
+                Tree::Operator {
+                    type_id, children, ..
+                } => (type_id, children),
+            };
+            let values = children
+                .iter()
+                .map(Tree::eval)
+                .collect::<Option<Vec<u64>>>()?;
+            match (type_id, values.as_slice()) {
+                (_, []) => None,
+                (0, _) => values.iter().try_fold(0u64, |acc, v| acc.checked_add(*v)),
+                (1, _) => values.iter().try_fold(1u64, |acc, v| acc.checked_mul(*v)),
+                (2, _) => values.iter().min().copied(),
+                (3, _) => values.iter().max().copied(),
+                (5, [first, second]) => Some(u64::from(first > second)),
+                (6, [first, second]) => Some(u64::from(first < second)),
+                (7, [first, second]) => Some(u64::from(first == second)),
+                _ => None,
+            }
+        }
+
+        fn encode(&self, bits: &mut String) {
+            match self {
+                Tree::Literal { version, value } => {
+                    bits.push_str(&format!("{version:03b}100"));
+                    let groups = (0..16)
+                        .rev()
+                        .map(|i| (value >> (i * 4)) & 0xf)
+                        .skip_while(|group| *group == 0)
+                        .collect::<Vec<u64>>();
+                    let groups = if groups.is_empty() { vec![0] } else { groups };
+                    for (i, group) in groups.iter().enumerate() {
+                        let more = u8::from(i + 1 < groups.len());
+                        bits.push_str(&format!("{more}{group:04b}"));
+                    }
+                }
+                Tree::Operator {
+                    version,
+                    type_id,
+                    by_count,
+                    children,
+                } => {
+                    let mut sub_bits = String::new();
+                    children
+                        .iter()
+                        .for_each(|child| child.encode(&mut sub_bits));
+                    bits.push_str(&format!("{version:03b}{type_id:03b}"));
+                    if *by_count {
+                        bits.push_str(&format!("1{:011b}", children.len()));
+                    } else {
+                        bits.push_str(&format!("0{:015b}", sub_bits.len()));
+                    }
+                    bits.push_str(&sub_bits);
+                }
+            }
+        }
+
+        fn to_hex(&self) -> String {
+            let mut bits = String::new();
+            self.encode(&mut bits);
+            while !bits.len().is_multiple_of(4) {
+                bits.push('0');
+            }
+            bits.as_bytes()
+                .chunks(4)
+                .map(|nibble| {
+                    let nibble = std::str::from_utf8(nibble).unwrap();
+                    format!("{:X}", u8::from_str_radix(nibble, 2).unwrap())
+                })
+                .collect()
+        }
+    }
+
+    /// Packets of up to four levels, mostly well-formed but with some operators lacking
+    /// children, comparisons of one child and values large enough to overflow.
+    fn tree() -> impl Strategy<Value = Tree> {
+        let value = prop_oneof![9 => 0..5000u64, 1 => any::<u64>()];
+        let literal = (0..8u8, value).prop_map(|(version, value)| Tree::Literal { version, value });
+        literal.prop_recursive(4, 32, 3, |inner| {
+            prop_oneof![
+                4 => (0..4u8, 0..8u8, any::<bool>(), vec(inner.clone(), 1..4)),
+                4 => (5..8u8, 0..8u8, any::<bool>(), vec(inner.clone(), 2)),
+                1 => (prop_oneof![0..4u8, 5..8u8], 0..8u8, any::<bool>(), vec(inner, 0..2)),
+            ]
+            .prop_map(|(type_id, version, by_count, children)| Tree::Operator {
+                version,
+                type_id,
+                by_count,
+                children,
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_part_one_matches_reference(tree in tree()) {
+            let packet = read_input(tree.to_hex());
+
+            // values are evaluated while parsing, so a malformed one fails the whole packet
+            match tree.eval() {
+                Some(_) => prop_assert_eq!(part_one(&packet?), tree.version_sum()),
+                None => prop_assert!(packet.is_err()),
+            }
+        }
+
+        #[test]
+        fn test_part_two_matches_reference(tree in tree()) {
+            let packet = read_input(tree.to_hex());
+
+            match tree.eval() {
+                Some(value) => prop_assert_eq!(part_two(&packet?), value),
+                None => prop_assert!(packet.is_err()),
+            }
+        }
+    }
+}
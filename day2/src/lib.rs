@@ -0,0 +1,155 @@
+mod submarine;
+
+pub use submarine::{
+    navigate, Aimed, Instruction, Interpreter, Overflow, Plain, Position, SubmarineModel,
+};
+
+use anyhow::Result;
+use aoc_core::{
+    parse::{finish, tag, IResult},
+    ParseError, Solution,
+};
+use nom::{
+    branch::alt,
+    character::complete::{self, line_ending, multispace0, multispace1},
+    combinator::{map, value},
+    multi::separated_list1,
+    sequence::{delimited, preceded, separated_pair},
+    Parser,
+};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Vec<Instruction>;
+    type Output1 = Position;
+    type Output2 = Position;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn part_one(input: &[Instruction]) -> Result<Position> {
+    navigate::<Plain>(input)
+}
+
+fn part_two(input: &[Instruction]) -> Result<Position> {
+    navigate::<Aimed>(input)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Instruction>> {
+    separated_list1(line_ending, parse_instruction).parse(input)
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        parse_command("forward ", Instruction::Forward),
+        parse_command("down ", Instruction::Down),
+        parse_command("up ", Instruction::Up),
+        parse_command("back ", Instruction::Back),
+        value(Instruction::Turn, tag("turn")),
+        parse_repeat,
+    ))
+    .parse(input)
+}
+
+fn parse_command(
+    name: &'static str,
+    instruction: fn(i32) -> Instruction,
+) -> impl FnMut(&str) -> IResult<&str, Instruction> {
+    move |input| map(preceded(tag(name), complete::i32), instruction).parse(input)
+}
+
+/// A block such as `repeat 3 { forward 2 down 1 }`, its instructions separated by spaces or lines.
+fn parse_repeat(input: &str) -> IResult<&str, Instruction> {
+    map(
+        separated_pair(
+            preceded(tag("repeat "), complete::u32),
+            tag(" {"),
+            delimited(
+                multispace0,
+                separated_list1(multispace1, parse_instruction),
+                (multispace0, tag("}")),
+            ),
+        ),
+        |(times, body)| Instruction::Repeat(times, body),
+    )
+    .parse(input)
+}
+
+fn parse_input(input: impl AsRef<str>) -> Result<Vec<Instruction>, ParseError> {
+    let input = input.as_ref();
+
+    finish(Day2::DAY, input, parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let Position { x, depth } = part_one(&parse_input(TESTDATA)?)?;
+
+        assert_eq!(x, 15);
+        assert_eq!(depth, 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let Position { x, depth } = part_two(&parse_input(TESTDATA)?)?;
+
+        assert_eq!(x, 15);
+        assert_eq!(depth, 60);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_new_commands() -> Result<()> {
+        let program =
+            parse_input("back 2\nrepeat 2 {\n  forward 1 turn\n  repeat 3 { up 1 }\n}\ndown 4\n")?;
+
+        assert_eq!(
+            program,
+            vec![
+                Instruction::Back(2),
+                Instruction::Repeat(
+                    2,
+                    vec![
+                        Instruction::Forward(1),
+                        Instruction::Turn,
+                        Instruction::Repeat(3, vec![Instruction::Up(1)])
+                    ]
+                ),
+                Instruction::Down(4)
+            ]
+        );
+        assert_eq!(part_one(&program)?, Position { x: -2, depth: -2 });
+
+        Ok(())
+    }
+}
@@ -1,140 +1,39 @@
-use anyhow::Result;
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{self, line_ending},
-    combinator::map,
-    multi::separated_list1,
-    sequence::preceded,
-    IResult, Parser,
-};
+use anyhow::{Error, Result};
+use aoc_core::{args::Command, input::Source, Solution};
+use day2::{Aimed, Day2, Interpreter, Plain, SubmarineModel};
 
-const DATA: &str = include_str!("input.txt");
+const USAGE: &str = "Usage: day2 --trace <plain|aimed> [PATH]";
 
+/// Solves the puzzle, or with `--trace <plain|aimed> [PATH]` prints the position after every
+/// step of the course in PATH (stdin when left out or `-`).
 fn main() -> Result<()> {
-    let (took, result) = took::took(|| parse_input(DATA));
-    println!("Time spent parsing: {}", took);
-    let input = result?;
-
-    let (took, (x, depth)) = took::took(|| part_one(&input));
-    println!("Result part one: {x} / {depth} = {}", x * depth);
-    println!("Time spent: {took}");
-
-    let (took, (x, depth)) = took::took(|| part_two(&input));
-    println!("Result part two: {x} / {depth} = {}", x * depth);
-    println!("Time spent: {took}");
-
-    Ok(())
+    let Some(mut command) = Command::from_env("--trace", USAGE) else {
+        return aoc_core::run::<Day2>();
+    };
+    let model = command.argument::<String>("model")?;
+    let source = command.source()?;
+    match model.as_str() {
+        "plain" => trace(Plain::default(), &source),
+        "aimed" => trace(Aimed::default(), &source),
+        model => Err(Error::msg(format!(
+            "Unknown model {model:?}, expected plain or aimed"
+        ))),
+    }
 }
 
-fn part_one(input: &[Instruction]) -> (i32, i32) {
-    input
-        .iter()
-        .fold((0, 0), |(x, depth), instruction| match instruction {
-            Instruction::Forward(value) => (x + value, depth),
-            Instruction::Down(value) => (x, depth + value),
-            Instruction::Up(value) => (x, depth - value),
-        })
-}
+fn trace(model: impl SubmarineModel, source: &Source) -> Result<()> {
+    let program = Day2::parse(&source.read()?)?;
+    let mut interpreter = Interpreter::new(model).traced();
+    let outcome = interpreter.run(&program);
 
-fn part_two(input: &[Instruction]) -> (i32, i32) {
-    let (x, depth, _) =
-        input.iter().fold(
-            (0, 0, 0),
-            |(x, depth, aim), instruction| match instruction {
-                Instruction::Forward(value) => (x + value, depth + aim * value, aim),
-                Instruction::Down(value) => (x, depth, aim + value),
-                Instruction::Up(value) => (x, depth, aim - value),
-            },
+    for (step, position) in interpreter.trace().iter().enumerate() {
+        println!(
+            "{:>6}: x {}, depth {}",
+            step + 1,
+            position.x,
+            position.depth
         );
-
-    (x, depth)
-}
-
-enum Instruction {
-    Forward(i32),
-    Down(i32),
-    Up(i32),
-}
-
-fn parse(input: &str) -> IResult<&str, Vec<Instruction>> {
-    separated_list1(line_ending, parse_line).parse(input)
-}
-
-fn parse_line(input: &str) -> IResult<&str, Instruction> {
-    alt((parse_forward, parse_down, parse_up)).parse(input)
-}
-
-fn parse_forward(input: &str) -> IResult<&str, Instruction> {
-    map(preceded(tag("forward "), complete::i32), |value| {
-        Instruction::Forward(value)
-    })
-    .parse(input)
-}
-
-fn parse_down(input: &str) -> IResult<&str, Instruction> {
-    map(preceded(tag("down "), complete::i32), |value| {
-        Instruction::Down(value)
-    })
-    .parse(input)
-}
-
-fn parse_up(input: &str) -> IResult<&str, Instruction> {
-    map(preceded(tag("up "), complete::i32), |value| {
-        Instruction::Up(value)
-    })
-    .parse(input)
-}
-
-fn parse_input(input: &'static str) -> Result<Vec<Instruction>> {
-    let (_, input) = parse(input)?;
-
-    Ok(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TESTDATA: &str = include_str!("test.txt");
-
-    #[test]
-    fn test_part_one_testdata() -> Result<()> {
-        let (x, depth) = part_one(&parse_input(TESTDATA)?);
-
-        assert_eq!(x, 15);
-        assert_eq!(depth, 10);
-
-        Ok(())
     }
 
-    #[test]
-    fn test_part_one() -> Result<()> {
-        let (x, depth) = part_one(&parse_input(DATA)?);
-
-        assert_eq!(x, 1967);
-        assert_eq!(depth, 1031);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_part_two_testdata() -> Result<()> {
-        let (x, depth) = part_two(&parse_input(TESTDATA)?);
-
-        assert_eq!(x, 15);
-        assert_eq!(depth, 60);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_part_two() -> Result<()> {
-        let (x, depth) = part_two(&parse_input(DATA)?);
-
-        assert_eq!(x, 1967);
-        assert_eq!(depth, 967791);
-
-        Ok(())
-    }
+    outcome
 }
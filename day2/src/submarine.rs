@@ -0,0 +1,279 @@
+use anyhow::{Error, Result};
+use std::fmt::{Display, Formatter};
+
+/// The position of the submarine; the answer is its product.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Position {
+    pub x: i32,
+    pub depth: i32,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.x as i64 * self.depth as i64)
+    }
+}
+
+/// A command that would take the submarine beyond the range of an `i32`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Overflow;
+
+/// How the submarine moves in response to each command.
+pub trait SubmarineModel {
+    fn forward(&mut self, units: i32) -> Result<(), Overflow>;
+
+    fn down(&mut self, units: i32) -> Result<(), Overflow>;
+
+    fn up(&mut self, units: i32) -> Result<(), Overflow> {
+        self.down(units.checked_neg().ok_or(Overflow)?)
+    }
+
+    fn back(&mut self, units: i32) -> Result<(), Overflow> {
+        self.forward(units.checked_neg().ok_or(Overflow)?)
+    }
+
+    /// Turns around, so forward moves the other way from now on.
+    fn turn(&mut self);
+
+    fn position(&self) -> Position;
+}
+
+/// The first reading of the commands: down and up change the depth directly.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Plain {
+    position: Position,
+    heading: i32,
+}
+
+impl Default for Plain {
+    fn default() -> Self {
+        Plain {
+            position: Position::default(),
+            heading: 1,
+        }
+    }
+}
+
+impl SubmarineModel for Plain {
+    fn forward(&mut self, units: i32) -> Result<(), Overflow> {
+        let step = units.checked_mul(self.heading).ok_or(Overflow)?;
+        self.position.x = self.position.x.checked_add(step).ok_or(Overflow)?;
+        Ok(())
+    }
+
+    fn down(&mut self, units: i32) -> Result<(), Overflow> {
+        self.position.depth = self.position.depth.checked_add(units).ok_or(Overflow)?;
+        Ok(())
+    }
+
+    fn turn(&mut self) {
+        self.heading = -self.heading;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+}
+
+/// The reading of the manual: down and up change the aim, and moving forward dives along it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Aimed {
+    position: Position,
+    aim: i32,
+    heading: i32,
+}
+
+impl Default for Aimed {
+    fn default() -> Self {
+        Aimed {
+            position: Position::default(),
+            aim: 0,
+            heading: 1,
+        }
+    }
+}
+
+impl SubmarineModel for Aimed {
+    fn forward(&mut self, units: i32) -> Result<(), Overflow> {
+        let step = units.checked_mul(self.heading).ok_or(Overflow)?;
+        let dive = units.checked_mul(self.aim).ok_or(Overflow)?;
+        self.position.x = self.position.x.checked_add(step).ok_or(Overflow)?;
+        self.position.depth = self.position.depth.checked_add(dive).ok_or(Overflow)?;
+        Ok(())
+    }
+
+    fn down(&mut self, units: i32) -> Result<(), Overflow> {
+        self.aim = self.aim.checked_add(units).ok_or(Overflow)?;
+        Ok(())
+    }
+
+    fn turn(&mut self) {
+        self.heading = -self.heading;
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+    Back(i32),
+    Turn,
+    Repeat(u32, Vec<Instruction>),
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Forward(units) => write!(f, "forward {units}"),
+            Instruction::Down(units) => write!(f, "down {units}"),
+            Instruction::Up(units) => write!(f, "up {units}"),
+            Instruction::Back(units) => write!(f, "back {units}"),
+            Instruction::Turn => write!(f, "turn"),
+            Instruction::Repeat(times, body) => {
+                write!(f, "repeat {times} {{")?;
+                body.iter().try_for_each(|i| write!(f, " {i}"))?;
+                write!(f, " }}")
+            }
+        }
+    }
+}
+
+/// Runs a program of instructions against a model, one step per movement command.
+pub struct Interpreter<M> {
+    model: M,
+    steps: usize,
+    trace: Option<Vec<Position>>,
+}
+
+impl<M: SubmarineModel> Interpreter<M> {
+    pub fn new(model: M) -> Self {
+        Interpreter {
+            model,
+            steps: 0,
+            trace: None,
+        }
+    }
+
+    /// Records the position after every step.
+    pub fn traced(mut self) -> Self {
+        self.trace = Some(Vec::new());
+        self
+    }
+
+    pub fn run(&mut self, program: &[Instruction]) -> Result<()> {
+        for instruction in program {
+            match instruction {
+                Instruction::Repeat(times, body) => {
+                    for _ in 0..*times {
+                        self.run(body)?;
+                    }
+                }
+                instruction => self.step(instruction)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn position(&self) -> Position {
+        self.model.position()
+    }
+
+    /// The positions after every step so far, empty unless [`traced`](Self::traced).
+    pub fn trace(&self) -> &[Position] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    fn step(&mut self, instruction: &Instruction) -> Result<()> {
+        self.steps += 1;
+        let moved = match instruction {
+            Instruction::Forward(units) => self.model.forward(*units),
+            Instruction::Down(units) => self.model.down(*units),
+            Instruction::Up(units) => self.model.up(*units),
+            Instruction::Back(units) => self.model.back(*units),
+            Instruction::Turn => {
+                self.model.turn();
+                Ok(())
+            }
+            Instruction::Repeat(..) => unreachable!("repeats are unrolled by run"),
+        };
+        moved.map_err(|Overflow| {
+            Error::msg(format!(
+                "Step {} ({instruction}) takes the submarine out of range",
+                self.steps
+            ))
+        })?;
+
+        if let Some(trace) = &mut self.trace {
+            trace.push(self.model.position());
+        }
+
+        Ok(())
+    }
+}
+
+/// Runs a program against a model from its starting position.
+pub fn navigate<M: SubmarineModel + Default>(program: &[Instruction]) -> Result<Position> {
+    let mut interpreter = Interpreter::new(M::default());
+    interpreter.run(program)?;
+
+    Ok(interpreter.position())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Instruction::*;
+
+    #[test]
+    fn test_new_commands() -> Result<()> {
+        let program = vec![
+            Repeat(2, vec![Forward(3), Down(1)]),
+            Back(2),
+            Turn,
+            Forward(1),
+        ];
+
+        assert_eq!(navigate::<Plain>(&program)?, Position { x: 3, depth: 2 });
+        assert_eq!(navigate::<Aimed>(&program)?, Position { x: 3, depth: 1 });
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trace() -> Result<()> {
+        let mut interpreter = Interpreter::new(Aimed::default()).traced();
+        interpreter.run(&[Down(2), Repeat(2, vec![Forward(1)])])?;
+
+        assert_eq!(
+            interpreter.trace(),
+            [
+                Position { x: 0, depth: 0 },
+                Position { x: 1, depth: 2 },
+                Position { x: 2, depth: 4 }
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_overflow() {
+        let program = vec![Down(1), Repeat(3, vec![Forward(i32::MAX / 2)])];
+
+        let error = navigate::<Plain>(&program).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Step 4 (forward {}) takes the submarine out of range",
+                i32::MAX / 2
+            )
+        );
+    }
+}
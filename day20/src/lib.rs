@@ -0,0 +1,127 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{finish, one_of, IResult},
+    ParseError, Solution,
+};
+use grid::{parse::pixels, InfiniteGrid, Position, ADJACENT};
+use nom::{
+    character::complete::line_ending,
+    combinator::map,
+    multi::{count, many1},
+    sequence::separated_pair,
+    Parser,
+};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = (Vec<bool>, InfiniteGrid<bool>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        let (key, grid) = input;
+
+        Ok(part_one(key, grid))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        let (key, grid) = input;
+
+        Ok(part_two(key, grid))
+    }
+}
+
+fn part_one(key: &[bool], image: &InfiniteGrid<bool>) -> usize {
+    lit_after(key, image, 2)
+}
+
+fn part_two(key: &[bool], image: &InfiniteGrid<bool>) -> usize {
+    lit_after(key, image, 50)
+}
+
+fn lit_after(key: &[bool], image: &InfiniteGrid<bool>, steps: usize) -> usize {
+    let image = (0..steps).fold(image.clone(), |image, _| enhance(key, &image));
+
+    image.window().values().filter(|lit| **lit).count()
+}
+
+fn enhance(key: &[bool], image: &InfiniteGrid<bool>) -> InfiniteGrid<bool> {
+    // the endless dark (or lit) background turns into the first (or last) pixel of the key
+    let background = key[if *image.background() { 511 } else { 0 }];
+
+    image.step(1, background, |position| key[index(image, position)])
+}
+
+/// The index into the key of a pixel, read from the 3x3 square around it.
+fn index(image: &InfiniteGrid<bool>, (x, y): Position) -> usize {
+    let (above, below) = ADJACENT.split_at(4);
+    above
+        .iter()
+        .chain([&(0, 0)])
+        .chain(below)
+        .fold(0, |index, (dx, dy)| {
+            index << 1 | *image.get((x + dx, y + dy)) as usize
+        })
+}
+
+fn parse(input: &str) -> IResult<&str, (Vec<bool>, InfiniteGrid<bool>)> {
+    separated_pair(parse_line, count(line_ending, 2), parse_image).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Vec<bool>> {
+    many1(map(one_of("#."), |c| c == '#')).parse(input)
+}
+
+fn parse_image(input: &str) -> IResult<&str, InfiniteGrid<bool>> {
+    map(pixels, |window| InfiniteGrid::new(window, false)).parse(input)
+}
+
+fn read_input(data: impl AsRef<str>) -> Result<(Vec<bool>, InfiniteGrid<bool>), ParseError> {
+    let data = data.as_ref();
+
+    finish(Day20::DAY, data, parse(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_one(&input.0, &input.1);
+
+        assert_eq!(count, 35);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_two(&input.0, &input.1);
+
+        assert_eq!(count, 3351);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use crate::row::{BitVec, Row};
+use grid::Grid;
+use num_bigint::BigUint;
+use std::fmt::{Display, Formatter};
+
+/// The gamma and epsilon rates; the answer is their product.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PowerConsumption {
+    pub gamma: BigUint,
+    pub epsilon: BigUint,
+}
+
+impl Display for PowerConsumption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", &self.gamma * &self.epsilon)
+    }
+}
+
+/// The oxygen generator and CO2 scrubber ratings; the answer is their product.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LifeSupport {
+    pub oxygen: BigUint,
+    pub co2: BigUint,
+}
+
+impl Display for LifeSupport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", &self.oxygen * &self.co2)
+    }
+}
+
+/// Everything the diagnostic report tells about the submarine.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiagnosticReport {
+    pub width: usize,
+    pub rows: usize,
+    pub power_consumption: PowerConsumption,
+    pub life_support: LifeSupport,
+}
+
+/// A report with its rows packed into words and, as an index for counting and filtering them,
+/// every column packed into a bitset over the rows.
+#[derive(Clone, Debug)]
+pub struct Diagnostic<R> {
+    width: usize,
+    rows: Vec<R>,
+    columns: Vec<Vec<u64>>,
+}
+
+impl<R: Row> Diagnostic<R> {
+    pub fn from_grid(grid: &Grid<bool>) -> Self {
+        let width = grid.width();
+        let limbs = grid.height().div_ceil(64);
+        let mut rows = Vec::with_capacity(grid.height());
+        let mut columns = vec![vec![0u64; limbs]; width];
+
+        for (index, bits) in grid.rows().enumerate() {
+            let mut row = R::zeros(width);
+            for (column, _) in bits.iter().enumerate().filter(|(_, bit)| **bit) {
+                row.set(column, width);
+                columns[column][index / 64] |= 1 << (index % 64);
+            }
+            rows.push(row);
+        }
+
+        Diagnostic {
+            width,
+            rows,
+            columns,
+        }
+    }
+
+    pub fn rows(&self) -> &[R] {
+        &self.rows
+    }
+
+    pub fn power_consumption(&self) -> PowerConsumption {
+        let all = self.all_rows();
+        let count = self.rows.len();
+        let mut gamma = R::zeros(self.width);
+        let mut epsilon = R::zeros(self.width);
+
+        for column in 0..self.width {
+            let ones = self.ones(column, &all);
+            if keep(ones, count, true) {
+                gamma.set(column, self.width);
+            }
+            if keep(ones, count, false) {
+                epsilon.set(column, self.width);
+            }
+        }
+
+        PowerConsumption {
+            gamma: gamma.to_biguint(),
+            epsilon: epsilon.to_biguint(),
+        }
+    }
+
+    pub fn life_support(&self) -> LifeSupport {
+        LifeSupport {
+            oxygen: self.rating(true).to_biguint(),
+            co2: self.rating(false).to_biguint(),
+        }
+    }
+
+    pub fn report(&self) -> DiagnosticReport {
+        DiagnosticReport {
+            width: self.width,
+            rows: self.rows.len(),
+            power_consumption: self.power_consumption(),
+            life_support: self.life_support(),
+        }
+    }
+
+    /// Narrows the rows down column by column to those with the most (or least) common bit,
+    /// until a single one is left.
+    fn rating(&self, most_common: bool) -> &R {
+        let mut selected = self.all_rows();
+
+        for (column, bits) in self.columns.iter().enumerate() {
+            let count = popcount(&selected);
+            if count == 1 {
+                break;
+            }
+            let kept = keep(self.ones(column, &selected), count, most_common);
+            for (selected, bits) in selected.iter_mut().zip(bits) {
+                *selected &= if kept { *bits } else { !*bits };
+            }
+        }
+
+        // rows left over after every column are all the same
+        let (limb, bits) = selected
+            .iter()
+            .enumerate()
+            .find(|(_, bits)| **bits != 0)
+            .expect("filtering always keeps a row");
+        &self.rows[limb * 64 + bits.trailing_zeros() as usize]
+    }
+
+    /// The selection of every row.
+    fn all_rows(&self) -> Vec<u64> {
+        let count = self.rows.len();
+        (0..count.div_ceil(64))
+            .map(|limb| match count - limb * 64 {
+                rest if rest >= 64 => u64::MAX,
+                rest => (1 << rest) - 1,
+            })
+            .collect()
+    }
+
+    /// How many of the selected rows have a one in `column`.
+    fn ones(&self, column: usize, selected: &[u64]) -> usize {
+        self.columns[column]
+            .iter()
+            .zip(selected)
+            .map(|(bits, selected)| (bits & selected).count_ones() as usize)
+            .sum()
+    }
+}
+
+fn popcount(bits: &[u64]) -> usize {
+    bits.iter().map(|b| b.count_ones() as usize).sum()
+}
+
+/// The bit to keep of a column holding `ones` ones among `count` rows: the most common one, or
+/// the least common one, with ties going to 1 and 0 respectively. A column without one of the
+/// bits keeps the other.
+fn keep(ones: usize, count: usize, most_common: bool) -> bool {
+    match ones {
+        0 => false,
+        ones if ones == count => true,
+        ones if most_common => ones * 2 >= count,
+        ones => ones * 2 < count,
+    }
+}
+
+/// A diagnostic report in the narrowest word its rows fit in.
+#[derive(Clone, Debug)]
+pub enum Report {
+    Narrow(Diagnostic<u64>),
+    Wide(Diagnostic<u128>),
+    Huge(Diagnostic<BitVec>),
+}
+
+impl Report {
+    pub fn from_grid(grid: &Grid<bool>) -> Self {
+        match grid.width() {
+            0..=64 => Report::Narrow(Diagnostic::from_grid(grid)),
+            65..=128 => Report::Wide(Diagnostic::from_grid(grid)),
+            _ => Report::Huge(Diagnostic::from_grid(grid)),
+        }
+    }
+
+    pub fn power_consumption(&self) -> PowerConsumption {
+        match self {
+            Report::Narrow(diagnostic) => diagnostic.power_consumption(),
+            Report::Wide(diagnostic) => diagnostic.power_consumption(),
+            Report::Huge(diagnostic) => diagnostic.power_consumption(),
+        }
+    }
+
+    pub fn life_support(&self) -> LifeSupport {
+        match self {
+            Report::Narrow(diagnostic) => diagnostic.life_support(),
+            Report::Wide(diagnostic) => diagnostic.life_support(),
+            Report::Huge(diagnostic) => diagnostic.life_support(),
+        }
+    }
+
+    pub fn report(&self) -> DiagnosticReport {
+        match self {
+            Report::Narrow(diagnostic) => diagnostic.report(),
+            Report::Wide(diagnostic) => diagnostic.report(),
+            Report::Huge(diagnostic) => diagnostic.report(),
+        }
+    }
+}
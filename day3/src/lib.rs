@@ -0,0 +1,141 @@
+mod diagnostic;
+mod row;
+
+pub use diagnostic::{Diagnostic, DiagnosticReport, LifeSupport, PowerConsumption, Report};
+pub use row::{BitVec, Row};
+
+use anyhow::Result;
+use aoc_core::{
+    parse::{finish, one_of, IResult},
+    ParseError, Solution,
+};
+use grid::parse::rows;
+use nom::{combinator::map, Parser};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Report;
+    type Output1 = PowerConsumption;
+    type Output2 = LifeSupport;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn part_one(report: &Report) -> Result<PowerConsumption> {
+    Ok(report.power_consumption())
+}
+
+fn part_two(report: &Report) -> Result<LifeSupport> {
+    Ok(report.life_support())
+}
+
+fn parse(input: &str) -> IResult<&str, Report> {
+    map(rows(map(one_of("01"), |c| c == '1')), |grid| {
+        Report::from_grid(&grid)
+    })
+    .parse(input)
+}
+
+fn parse_input(input: impl AsRef<str>) -> Result<Report, ParseError> {
+    let input = input.as_ref();
+
+    finish(Day3::DAY, input, parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigUint;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let PowerConsumption { gamma, epsilon } = part_one(&parse_input(TESTDATA)?)?;
+
+        assert_eq!(gamma, BigUint::from(22u32));
+        assert_eq!(epsilon, BigUint::from(9u32));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let LifeSupport { oxygen, co2 } = part_two(&parse_input(TESTDATA)?)?;
+
+        assert_eq!(oxygen, BigUint::from(23u32));
+        assert_eq!(co2, BigUint::from(10u32));
+
+        Ok(())
+    }
+
+    /// The same report with every row left-padded by `padding` zeros.
+    fn padded(padding: usize) -> Result<Report> {
+        let data = TESTDATA
+            .lines()
+            .map(|line| format!("{}{line}", "0".repeat(padding)))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        Ok(parse_input(data)?)
+    }
+
+    #[test]
+    fn test_widths_agree() -> Result<()> {
+        let expected = parse_input(TESTDATA)?.report();
+
+        for padding in [59, 60, 100, 123, 124, 300] {
+            let report = padded(padding)?;
+            let width = 5 + padding;
+            match (&report, width) {
+                (Report::Narrow(_), ..=64)
+                | (Report::Wide(_), 65..=128)
+                | (Report::Huge(_), 129..) => {}
+                _ => panic!("wrong packing for a width of {width}"),
+            }
+
+            let report = report.report();
+            assert_eq!(report.power_consumption, expected.power_consumption);
+            assert_eq!(report.life_support, expected.life_support);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_wide_rows() -> Result<()> {
+        let one = "1".repeat(130);
+        let zero = "0".repeat(130);
+        let report = parse_input(format!("{one}\n{zero}\n{one}"))?.report();
+        let ones = (BigUint::from(1u8) << 130u32) - 1u8;
+
+        assert_eq!((report.width, report.rows), (130, 3));
+        assert_eq!(report.power_consumption.gamma, ones);
+        assert_eq!(report.power_consumption.epsilon, BigUint::from(0u8));
+        assert_eq!(report.life_support.oxygen, ones);
+        assert_eq!(report.life_support.co2, BigUint::from(0u8));
+
+        Ok(())
+    }
+}
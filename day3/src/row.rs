@@ -0,0 +1,106 @@
+use num_bigint::BigUint;
+use std::fmt::Debug;
+
+/// The bits of a report line packed into a word, the leftmost column being the most significant.
+pub trait Row: Clone + Debug + Eq {
+    /// A row of `width` zero bits.
+    fn zeros(width: usize) -> Self;
+
+    /// Sets the bit of `column`, counted from the left of a row of `width` bits.
+    fn set(&mut self, column: usize, width: usize);
+
+    fn get(&self, column: usize, width: usize) -> bool;
+
+    fn to_biguint(&self) -> BigUint;
+}
+
+macro_rules! impl_row {
+    ($word:ty) => {
+        impl Row for $word {
+            fn zeros(_: usize) -> Self {
+                0
+            }
+
+            fn set(&mut self, column: usize, width: usize) {
+                *self |= 1 << (width - 1 - column);
+            }
+
+            fn get(&self, column: usize, width: usize) -> bool {
+                self >> (width - 1 - column) & 1 == 1
+            }
+
+            fn to_biguint(&self) -> BigUint {
+                BigUint::from(*self)
+            }
+        }
+    };
+}
+
+impl_row!(u64);
+impl_row!(u128);
+
+/// A row too wide for a `u128`, kept as 64-bit limbs with the least significant one first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BitVec {
+    limbs: Vec<u64>,
+}
+
+impl Row for BitVec {
+    fn zeros(width: usize) -> Self {
+        BitVec {
+            limbs: vec![0; width.div_ceil(64)],
+        }
+    }
+
+    fn set(&mut self, column: usize, width: usize) {
+        let bit = width - 1 - column;
+        self.limbs[bit / 64] |= 1 << (bit % 64);
+    }
+
+    fn get(&self, column: usize, width: usize) -> bool {
+        let bit = width - 1 - column;
+        self.limbs[bit / 64] >> (bit % 64) & 1 == 1
+    }
+
+    fn to_biguint(&self) -> BigUint {
+        let digits = self
+            .limbs
+            .iter()
+            .flat_map(|limb| [*limb as u32, (limb >> 32) as u32])
+            .collect();
+        BigUint::new(digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row<R: Row>(bits: &str) -> R {
+        let mut row = R::zeros(bits.len());
+        for (column, bit) in bits.chars().enumerate() {
+            if bit == '1' {
+                row.set(column, bits.len());
+            }
+        }
+        row
+    }
+
+    #[test]
+    fn test_rows_agree() {
+        let bits = "10110";
+
+        assert_eq!(row::<u64>(bits), 22);
+        assert_eq!(row::<u128>(bits), 22);
+        assert_eq!(row::<BitVec>(bits).to_biguint(), BigUint::from(22u8));
+        assert!(row::<BitVec>(bits).get(2, 5));
+    }
+
+    #[test]
+    fn test_wide_row() {
+        let bits = format!("1{}1", "0".repeat(198));
+        let expected = (BigUint::from(1u8) << 199u32) + 1u8;
+
+        assert_eq!(row::<BitVec>(&bits).to_biguint(), expected);
+    }
+}
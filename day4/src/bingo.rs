@@ -0,0 +1,394 @@
+use anyhow::{Error, Result};
+use grid::{Grid, Point};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// The number that completed a card and the sum of its unmarked numbers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Win {
+    pub called_number: u8,
+    pub card_sum: u32,
+}
+
+impl Win {
+    pub fn score(&self) -> u32 {
+        self.called_number as u32 * self.card_sum
+    }
+}
+
+impl Display for Win {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.score())
+    }
+}
+
+/// A bingo card of any width and height.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Card {
+    numbers: Grid<u8>,
+}
+
+impl Card {
+    /// A card of the numbers, failing when there are none.
+    pub fn new(numbers: Grid<u8>) -> Result<Self> {
+        if numbers.is_empty() {
+            return Err(Error::msg("A card needs at least one number"));
+        }
+
+        Ok(Card { numbers })
+    }
+
+    pub fn numbers(&self) -> &Grid<u8> {
+        &self.numbers
+    }
+
+    /// The corners of the card, each only once on a card a single number wide or high. Cards are
+    /// never empty, so there is always at least one.
+    fn corners(&self) -> Vec<Point> {
+        let (right, bottom) = (self.numbers.width() - 1, self.numbers.height() - 1);
+        let mut corners = vec![(0, 0), (right, 0), (0, bottom), (right, bottom)];
+        corners.sort_unstable();
+        corners.dedup();
+        corners
+    }
+}
+
+/// The line or shape of marked numbers that won a card.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Pattern {
+    Row(usize),
+    Column(usize),
+    /// From the top left to the bottom right.
+    Diagonal,
+    /// From the top right to the bottom left.
+    AntiDiagonal,
+    Corners,
+    FullCard,
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pattern::Row(row) => write!(f, "row {}", row + 1),
+            Pattern::Column(column) => write!(f, "column {}", column + 1),
+            Pattern::Diagonal => write!(f, "diagonal"),
+            Pattern::AntiDiagonal => write!(f, "anti-diagonal"),
+            Pattern::Corners => write!(f, "corners"),
+            Pattern::FullCard => write!(f, "full card"),
+        }
+    }
+}
+
+/// The patterns that win a card. The puzzle only counts rows and columns, which is the default.
+///
+/// Diagonals only count on square cards.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rules {
+    pub rows: bool,
+    pub columns: bool,
+    pub diagonals: bool,
+    pub corners: bool,
+    pub full_card: bool,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            rows: true,
+            columns: true,
+            diagonals: false,
+            corners: false,
+            full_card: false,
+        }
+    }
+}
+
+impl FromStr for Rules {
+    type Err = Error;
+
+    /// Parses a comma separated list of patterns such as `rows,columns,diagonals`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rules = Rules {
+            rows: false,
+            columns: false,
+            ..Rules::default()
+        };
+        for pattern in s.split(',').map(str::trim) {
+            match pattern {
+                "rows" => rules.rows = true,
+                "columns" => rules.columns = true,
+                "diagonals" => rules.diagonals = true,
+                "corners" => rules.corners = true,
+                "full-card" => rules.full_card = true,
+                pattern => {
+                    return Err(Error::msg(format!(
+                        "Unknown pattern {pattern:?}, expected rows, columns, diagonals, corners \
+                         or full-card"
+                    )));
+                }
+            }
+        }
+
+        Ok(rules)
+    }
+}
+
+/// The marks on a card during a game, counted per line so a win is seen without rescanning.
+struct Marks {
+    marked: Grid<bool>,
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+    diagonals: [usize; 2],
+    corner_points: Vec<Point>,
+    corners: usize,
+    total: usize,
+    unmarked_sum: u32,
+}
+
+impl Marks {
+    fn new(card: &Card) -> Self {
+        let numbers = &card.numbers;
+        Marks {
+            marked: Grid::filled(numbers.width(), numbers.height(), false),
+            rows: vec![0; numbers.height()],
+            columns: vec![0; numbers.width()],
+            diagonals: [0; 2],
+            corner_points: card.corners(),
+            corners: 0,
+            total: 0,
+            unmarked_sum: numbers.values().map(|n| *n as u32).sum(),
+        }
+    }
+
+    /// Marks a number of `card` and returns the first pattern of `rules` it completes.
+    fn mark(&mut self, card: &Card, (x, y): Point, rules: &Rules) -> Option<Pattern> {
+        if self.marked[(x, y)] {
+            return None;
+        }
+        self.marked[(x, y)] = true;
+        self.unmarked_sum -= card.numbers[(x, y)] as u32;
+        self.rows[y] += 1;
+        self.columns[x] += 1;
+        self.total += 1;
+
+        let (width, height) = (self.marked.width(), self.marked.height());
+        let square = width == height;
+        if square && x == y {
+            self.diagonals[0] += 1;
+        }
+        if square && x + y == width - 1 {
+            self.diagonals[1] += 1;
+        }
+        let corner = self.corner_points.contains(&(x, y));
+        if corner {
+            self.corners += 1;
+        }
+
+        if rules.rows && self.rows[y] == width {
+            Some(Pattern::Row(y))
+        } else if rules.columns && self.columns[x] == height {
+            Some(Pattern::Column(x))
+        } else if rules.diagonals && square && x == y && self.diagonals[0] == width {
+            Some(Pattern::Diagonal)
+        } else if rules.diagonals && square && x + y == width - 1 && self.diagonals[1] == width {
+            Some(Pattern::AntiDiagonal)
+        } else if rules.corners && corner && self.corners == self.corner_points.len() {
+            Some(Pattern::Corners)
+        } else if rules.full_card && self.total == self.marked.len() {
+            Some(Pattern::FullCard)
+        } else {
+            None
+        }
+    }
+}
+
+/// A card winning the game.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Winner {
+    /// The index of the card among all cards.
+    pub card: usize,
+    /// The index of the winning draw among the called numbers.
+    pub draw: usize,
+    pub pattern: Pattern,
+    pub win: Win,
+}
+
+/// The cards in the order they won. Cards winning on the same draw are listed in card order.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameLog {
+    pub winners: Vec<Winner>,
+    /// The cards that never won, in card order.
+    pub losers: Vec<usize>,
+}
+
+impl GameLog {
+    pub fn first(&self) -> Option<&Winner> {
+        self.winners.first()
+    }
+
+    pub fn last(&self) -> Option<&Winner> {
+        self.winners.last()
+    }
+
+    /// The cards winning on `draw`.
+    pub fn winners_on(&self, draw: usize) -> impl Iterator<Item = &Winner> {
+        self.winners
+            .iter()
+            .filter(move |winner| winner.draw == draw)
+    }
+}
+
+impl Display for GameLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (place, winner) in self.winners.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4}. card {} on draw {} ({}) with {}: {} * {} = {}",
+                place + 1,
+                winner.card + 1,
+                winner.draw + 1,
+                winner.win.called_number,
+                winner.pattern,
+                winner.win.card_sum,
+                winner.win.called_number,
+                winner.win.score()
+            )?;
+        }
+        if !self.losers.is_empty() {
+            let losers = self
+                .losers
+                .iter()
+                .map(|card| (card + 1).to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+            writeln!(f, "Never won: {losers}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Calls the numbers one by one on all cards at once, logging every card as it wins.
+pub fn play(called_numbers: &[u8], cards: &[Card], rules: &Rules) -> GameLog {
+    // where each number is on the cards, so a call only touches the cards that have it
+    let mut places: HashMap<u8, Vec<(usize, Point)>> = HashMap::new();
+    for (index, card) in cards.iter().enumerate() {
+        for (point, number) in card.numbers.iter() {
+            places.entry(*number).or_default().push((index, point));
+        }
+    }
+
+    let mut marks = cards.iter().map(Marks::new).collect::<Vec<Marks>>();
+    let mut won = vec![false; cards.len()];
+    let mut log = GameLog::default();
+
+    for (draw, called_number) in called_numbers.iter().enumerate() {
+        if log.winners.len() == cards.len() {
+            break;
+        }
+        let Some(places) = places.get(called_number) else {
+            continue;
+        };
+
+        // a card is only scored once every copy of the number on it is marked
+        let mut completed: Vec<(usize, Pattern)> = Vec::new();
+        for (index, point) in places {
+            if won[*index] {
+                continue;
+            }
+            if let Some(pattern) = marks[*index].mark(&cards[*index], *point, rules)
+                && completed.last().is_none_or(|(last, _)| last != index)
+            {
+                completed.push((*index, pattern));
+            }
+        }
+
+        for (index, pattern) in completed {
+            won[index] = true;
+            log.winners.push(Winner {
+                card: index,
+                draw,
+                pattern,
+                win: Win {
+                    called_number: *called_number,
+                    card_sum: marks[index].unmarked_sum,
+                },
+            });
+        }
+    }
+
+    log.losers = (0..cards.len()).filter(|index| !won[*index]).collect();
+    log
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(width: usize, numbers: &[u8]) -> Card {
+        let rows = numbers.chunks(width).map(<[u8]>::to_vec).collect();
+        Card::new(Grid::from_rows(rows).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_empty_card() {
+        let empty = Card::new(Grid::from_fn(0, 0, |_| 0));
+        assert_eq!(
+            empty.unwrap_err().to_string(),
+            "A card needs at least one number"
+        );
+    }
+
+    #[test]
+    fn test_patterns() {
+        let cards = [card(3, &[1, 2, 3, 4, 5, 6, 7, 8, 9])];
+        let diagonals = Rules {
+            diagonals: true,
+            ..Rules::default()
+        };
+        let corners = Rules {
+            rows: false,
+            columns: false,
+            corners: true,
+            ..Rules::default()
+        };
+
+        let log = play(&[3, 5, 7], &cards, &Rules::default());
+        assert_eq!(log.losers, vec![0]);
+
+        let log = play(&[3, 5, 7], &cards, &diagonals);
+        assert_eq!(log.first().map(|w| w.pattern), Some(Pattern::AntiDiagonal));
+        assert_eq!(log.first().map(|w| w.win.card_sum), Some(30));
+
+        let log = play(&[1, 3, 2, 7, 9], &cards, &corners);
+        assert_eq!(
+            log.first().map(|w| (w.draw, w.pattern)),
+            Some((4, Pattern::Corners))
+        );
+    }
+
+    #[test]
+    fn test_rectangular_cards_and_ties() -> anyhow::Result<()> {
+        let cards = [
+            card(4, &[1, 2, 3, 4, 5, 6, 7, 8]),
+            card(3, &[9, 8, 2, 5, 6, 4]),
+        ];
+
+        let log = play(&[9, 2, 8, 4], &cards, &"columns,full-card".parse()?);
+
+        assert_eq!(log.winners.len(), 2);
+        assert_eq!(log.winners_on(2).count(), 0);
+        assert_eq!(
+            log.winners_on(3)
+                .map(|w| (w.card, w.pattern))
+                .collect::<Vec<_>>(),
+            vec![(0, Pattern::Column(3)), (1, Pattern::Column(2))]
+        );
+        assert!("rows,stars".parse::<Rules>().is_err());
+
+        Ok(())
+    }
+}
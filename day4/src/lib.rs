@@ -0,0 +1,257 @@
+mod bingo;
+
+pub use bingo::{play, Card, GameLog, Pattern, Rules, Win, Winner};
+
+use anyhow::{Error, Result};
+use aoc_core::{
+    parse::{finish, IResult},
+    ParseError, Solution,
+};
+use grid::parse::rows;
+use nom::{
+    character::complete::{self, line_ending, space0},
+    combinator::map_res,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, terminated},
+    Parser,
+};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = (Vec<u8>, Vec<Card>);
+    type Output1 = Win;
+    type Output2 = Win;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        let (called_numbers, cards) = input;
+
+        part_one(called_numbers, cards).ok_or_else(|| Error::msg("No result found"))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        let (called_numbers, cards) = input;
+
+        part_two(called_numbers, cards).ok_or_else(|| Error::msg("No result found"))
+    }
+}
+
+fn part_one(called_numbers: &[u8], cards: &[Card]) -> Option<Win> {
+    play(called_numbers, cards, &Rules::default())
+        .first()
+        .map(|winner| winner.win)
+}
+
+fn part_two(called_numbers: &[u8], cards: &[Card]) -> Option<Win> {
+    let log = play(called_numbers, cards, &Rules::default());
+
+    // the last card only counts once every card has won
+    log.losers
+        .is_empty()
+        .then(|| log.last().map(|winner| winner.win))
+        .flatten()
+}
+
+fn parse(input: &str) -> IResult<&str, (Vec<u8>, Vec<Card>)> {
+    separated_pair(parse_called_numbers, line_ending, parse_cards).parse(input)
+}
+
+fn parse_called_numbers(input: &str) -> IResult<&str, Vec<u8>> {
+    terminated(
+        separated_list1(complete::char(','), complete::u8),
+        line_ending,
+    )
+    .parse(input)
+}
+
+fn parse_cards(input: &str) -> IResult<&str, Vec<Card>> {
+    separated_list1((line_ending, line_ending), parse_card).parse(input)
+}
+
+/// Parses rows of numbers lined up with spaces into a card as wide as its first row.
+fn parse_card(input: &str) -> IResult<&str, Card> {
+    map_res(rows(preceded(space0, complete::u8)), Card::new).parse(input)
+}
+
+fn parse_input(input: impl AsRef<str>) -> Result<(Vec<u8>, Vec<Card>), ParseError> {
+    let input = input.as_ref();
+
+    finish(Day4::DAY, input, parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grid::Grid;
+    use proptest::{collection::vec, prelude::*, sample::subsequence};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let (called_numbers, cards) = parse_input(TESTDATA)?;
+
+        let Win {
+            called_number,
+            card_sum,
+        } = part_one(&called_numbers, &cards).expect("No result found");
+
+        assert_eq!(called_number, 24);
+        assert_eq!(card_sum, 188);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let (called_numbers, cards) = parse_input(TESTDATA)?;
+
+        let Win {
+            called_number,
+            card_sum,
+        } = part_two(&called_numbers, &cards).expect("No result found");
+
+        assert_eq!(called_number, 13);
+        assert_eq!(card_sum, 148);
+
+        Ok(())
+    }
+
+    /// The draw on which every card wins and its win, found by checking all rows and columns
+    /// after every call.
+    fn wins_naive(called_numbers: &[u8], cards: &[Vec<u8>]) -> Vec<Option<(usize, Win)>> {
+        cards
+            .iter()
+            .map(|card| {
+                let mut marked = [false; 25];
+                called_numbers
+                    .iter()
+                    .enumerate()
+                    .find_map(|(draw, called_number)| {
+                        if let Some(pos) = card.iter().position(|n| n == called_number) {
+                            marked[pos] = true;
+                        }
+                        let row = (0..5).any(|r| (0..5).all(|c| marked[r * 5 + c]));
+                        let column = (0..5).any(|c| (0..5).all(|r| marked[r * 5 + c]));
+                        (row || column).then(|| {
+                            let card_sum = (0..25)
+                                .filter(|pos| !marked[*pos])
+                                .map(|pos| card[pos] as u32)
+                                .sum();
+                            let win = Win {
+                                called_number: *called_number,
+                                card_sum,
+                            };
+                            (draw, win)
+                        })
+                    })
+            })
+            .collect()
+    }
+
+    fn card(numbers: Vec<u8>) -> Card {
+        Card::new(Grid::from_fn(5, 5, |(x, y)| numbers[y * 5 + x])).unwrap()
+    }
+
+    fn render(called_numbers: &[u8], cards: &[Vec<u8>]) -> String {
+        let called_numbers = called_numbers
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<String>>()
+            .join(",");
+        let cards = cards
+            .iter()
+            .map(|card| {
+                card.chunks(5)
+                    .map(|row| {
+                        let row = row.iter().map(|n| format!("{n:>2}")).collect::<Vec<_>>();
+                        row.join(" ") + "\n"
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!("{called_numbers}\n\n{cards}")
+    }
+
+    /// A game where every number up to 99 gets called, so every card wins eventually.
+    fn game() -> impl Strategy<Value = (Vec<u8>, Vec<Vec<u8>>)> {
+        let numbers = (0..100).collect::<Vec<u8>>();
+        let card = subsequence(numbers.clone(), 25).prop_shuffle();
+
+        (Just(numbers).prop_shuffle(), vec(card, 1..8))
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip((called_numbers, cards) in game()) {
+            let (parsed_numbers, parsed_cards) = parse_input(render(&called_numbers, &cards))?;
+
+            prop_assert_eq!(parsed_numbers, called_numbers);
+            prop_assert_eq!(
+                parsed_cards
+                    .iter()
+                    .map(|card| card.numbers().values().copied().collect())
+                    .collect::<Vec<Vec<u8>>>(),
+                cards
+            );
+        }
+
+        #[test]
+        fn test_part_one_matches_reference((called_numbers, cards) in game()) {
+            let expected = wins_naive(&called_numbers, &cards)
+                .into_iter()
+                .flatten()
+                .min_by_key(|(draw, _)| *draw)
+                .map(|(_, win)| win);
+            let cards = cards.into_iter().map(card).collect::<Vec<Card>>();
+
+            prop_assert_eq!(part_one(&called_numbers, &cards), expected);
+        }
+
+        #[test]
+        fn test_part_two_matches_reference((called_numbers, cards) in game()) {
+            let expected = wins_naive(&called_numbers, &cards)
+                .into_iter()
+                .flatten()
+                .max_by_key(|(draw, _)| *draw)
+                .map(|(_, win)| win);
+            let cards = cards.into_iter().map(card).collect::<Vec<Card>>();
+
+            prop_assert_eq!(part_two(&called_numbers, &cards), expected);
+        }
+
+        #[test]
+        fn test_log_matches_reference((called_numbers, cards) in game()) {
+            let mut expected = wins_naive(&called_numbers, &cards)
+                .into_iter()
+                .enumerate()
+                .filter_map(|(card, win)| win.map(|(draw, win)| (draw, card, win)))
+                .collect::<Vec<_>>();
+            expected.sort_by_key(|(draw, card, _)| (*draw, *card));
+            let cards = cards.into_iter().map(card).collect::<Vec<Card>>();
+
+            let log = play(&called_numbers, &cards, &Rules::default());
+
+            prop_assert_eq!(
+                log.winners.iter().map(|w| (w.draw, w.card, w.win)).collect::<Vec<_>>(),
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,190 @@
+mod render;
+mod vents;
+
+pub use render::{VentMap, MAX_CELLS, MAX_PIXELS};
+pub use vents::{overlaps, Line, Orientation, Orientations, Point, LIMIT};
+
+use anyhow::Result;
+use aoc_core::{
+    parse::{finish, tag, Error, Expected, IResult},
+    ParseError, Solution,
+};
+use nom::{
+    character::complete::{self, line_ending},
+    combinator::map,
+    multi::separated_list1,
+    sequence::separated_pair,
+    Parser,
+};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Vec<Line>;
+    type Output1 = u128;
+    type Output2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        Ok(part_two(input))
+    }
+}
+
+fn part_one(lines: &[Line]) -> u128 {
+    overlaps(lines, Orientations::AXIS_ALIGNED)
+}
+
+fn part_two(lines: &[Line]) -> u128 {
+    overlaps(lines, Orientations::WITH_DIAGONALS)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Line>> {
+    separated_list1(line_ending, parse_line).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Line> {
+    map(
+        separated_pair(parse_point, tag(" -> "), parse_point),
+        |(p1, p2)| Line::new(p1, p2),
+    )
+    .parse(input)
+}
+
+fn parse_point(input: &str) -> IResult<&str, Point> {
+    separated_pair(parse_coordinate, complete::char(','), parse_coordinate).parse(input)
+}
+
+fn parse_coordinate(input: &str) -> IResult<&str, i64> {
+    let (rest, coordinate) = complete::i64(input)?;
+    if coordinate.unsigned_abs() > LIMIT as u64 {
+        return Err(nom::Err::Failure(Error::new(
+            input,
+            Expected::Other(format!("a coordinate within {LIMIT} of the origin")),
+        )));
+    }
+
+    Ok((rest, coordinate))
+}
+
+fn read_input(data: impl AsRef<str>) -> Result<Vec<Line>, ParseError> {
+    let data = data.as_ref();
+
+    finish(Day5::DAY, data, parse(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+    use std::collections::HashMap;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let lines = read_input(TESTDATA)?;
+
+        let count = part_one(&lines);
+
+        assert_eq!(count, 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let lines = read_input(TESTDATA)?;
+
+        let count = part_two(&lines);
+
+        assert_eq!(count, 12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_far_coordinates() -> Result<()> {
+        let far = LIMIT - 4;
+        let input = format!("-{far},0 -> {far},0\n0,-{far} -> 0,{far}\n-{far},0 -> 0,0\n");
+
+        assert_eq!(part_one(&read_input(input)?), far as u128 + 1);
+        assert!(read_input(format!("0,0 -> {},0", LIMIT + 1)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coordinate_out_of_range() {
+        let error = read_input("0,0 -> 1,1\n-9223372036854775808,0 -> 0,0").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+        assert!(error.expected.contains("a coordinate within"));
+    }
+
+    /// The number of points covered at least twice, found by walking every line.
+    fn overlaps_naive(lines: &[Line], orientations: Orientations) -> u128 {
+        let mut covered: HashMap<Point, usize> = HashMap::new();
+        for line in lines
+            .iter()
+            .filter(|line| orientations.contains(line.orientation()))
+        {
+            for point in line.points() {
+                *covered.entry(point).or_default() += 1;
+            }
+        }
+
+        covered.values().filter(|count| **count >= 2).count() as u128
+    }
+
+    fn line() -> impl Strategy<Value = Line> {
+        let point = || (-12i64..12, -12i64..12);
+        prop_oneof![
+            (point(), point()).prop_map(|(from, to)| Line::new(from, to)),
+            (point(), -12i64..12, any::<bool>()).prop_map(|((x, y), length, vertical)| {
+                let to = if vertical {
+                    (x, y + length)
+                } else {
+                    (x + length, y)
+                };
+                Line::new((x, y), to)
+            }),
+            (point(), -12i64..12, any::<bool>()).prop_map(|((x, y), length, rising)| {
+                let to = (x + length, if rising { y - length } else { y + length });
+                Line::new((x, y), to)
+            }),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_overlaps_match_reference(lines in vec(line(), 0..24)) {
+            for orientations in [
+                Orientations::AXIS_ALIGNED,
+                Orientations::WITH_DIAGONALS,
+                Orientations::ALL,
+            ] {
+                prop_assert_eq!(
+                    overlaps(&lines, orientations),
+                    overlaps_naive(&lines, orientations)
+                );
+            }
+        }
+    }
+}
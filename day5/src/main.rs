@@ -1,160 +1,57 @@
-use anyhow::Result;
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, line_ending},
-    combinator::map,
-    multi::separated_list1,
-    sequence::separated_pair,
-    IResult, Parser, ToUsize,
-};
-use std::{cmp::max, cmp::min};
-
-const DATA: &str = include_str!("input.txt");
-
+use anyhow::{Context, Error, Result};
+use aoc_core::{args::Command, Solution};
+use day5::{overlaps, Day5, Line, Orientations, VentMap};
+use std::fs;
+
+const COUNT_USAGE: &str = "Usage: day5 --count <horizontal,vertical,diagonal,sloped> [PATH]";
+const RENDER_USAGE: &str = "Usage: day5 --render <ascii|FILE.pgm|FILE.png> [--highlight ROW] \
+                            [--orientations LIST] [--scale PIXELS] [PATH]";
+
+/// Solves the puzzle, or with `--count <ORIENTATIONS> [PATH]` prints how many points the lines in
+/// PATH (stdin when left out or `-`) overlap at, counting only the lines with one of ORIENTATIONS,
+/// such as `horizontal,vertical,sloped`.
+///
+/// With `--render <ascii|FILE.pgm|FILE.png>` it draws the map of the lines instead, to the
+/// terminal or into an image, optionally highlighting the line on the 1-based input ROW.
 fn main() -> Result<()> {
-    let lines = read_input(DATA)?;
-
-    let (took, result) = took::took(|| part_one(&lines));
-    println!("Result part one: {result}");
-    println!("Time spent: {took}");
-
-    let (took, result) = took::took(|| part_two(&lines));
-    println!("Result part two: {result}");
-    println!("Time spent: {took}");
-
-    Ok(())
-}
-
-fn part_one(lines: &[Line]) -> usize {
-    let mut grid = create_grid();
-
-    fill_grid(
-        &mut grid,
-        lines.iter().filter(|line| line.horizontal || line.vertical),
-    );
-
-    count_grid(&grid)
-}
-
-fn part_two(lines: &[Line]) -> usize {
-    let mut grid = create_grid();
-
-    fill_grid(&mut grid, lines.iter());
-
-    count_grid(&grid)
-}
-
-fn create_grid() -> Vec<usize> {
-    let mut grid: Vec<usize> = Vec::new();
-    (0..1_000_000).for_each(|_| grid.push(0));
-    grid
-}
-
-fn fill_grid<'a, I: IntoIterator<Item = &'a Line>>(grid: &mut [usize], lines: I) {
-    for line in lines {
-        line.points()
-            .iter()
-            .for_each(|(x, y)| grid[y * 1000 + x] += 1);
-    }
-}
-
-fn count_grid(grid: &[usize]) -> usize {
-    grid.iter().filter(|val| val >= &&2).count()
-}
-
-struct Line {
-    x1: usize,
-    y1: usize,
-    x2: usize,
-    y2: usize,
-    horizontal: bool,
-    vertical: bool,
-}
+    if let Some(mut command) = Command::from_env("--count", COUNT_USAGE) {
+        let orientations = command.argument::<Orientations>("orientations")?;
+        let lines = read(command)?;
 
-impl Line {
-    pub fn new(p1: (usize, usize), p2: (usize, usize)) -> Self {
-        Line {
-            x1: p1.0,
-            y1: p1.1,
-            x2: p2.0,
-            y2: p2.1,
-            horizontal: p1.1 == p2.1,
-            vertical: p1.0 == p2.0,
-        }
-    }
-
-    pub fn points(&self) -> Vec<(usize, usize)> {
-        if self.horizontal {
-            let range = Self::calculate_range(self.x1, self.x2);
-            return range.map(|x| (x, self.y1)).collect::<Vec<(usize, usize)>>();
-        }
-        if self.vertical {
-            let range = Self::calculate_range(self.y1, self.y2);
-            return range.map(|y| (self.x1, y)).collect::<Vec<(usize, usize)>>();
-        }
-        let hor_range = Self::calculate_range(self.x1, self.x2);
-        let ver_range = Self::calculate_range(self.y1, self.y2);
-        hor_range.zip(ver_range).collect::<Vec<(usize, usize)>>()
+        println!("{}", overlaps(&lines, orientations));
+        return Ok(());
     }
-
-    fn calculate_range(a: usize, b: usize) -> Box<dyn Iterator<Item = usize>> {
-        let range = min(a, b)..=max(a, b);
-        if a > b {
-            return Box::new(range.rev());
-        }
-        Box::new(range)
+    match Command::from_env("--render", RENDER_USAGE) {
+        Some(command) => render(command),
+        None => aoc_core::run::<Day5>(),
     }
 }
 
-fn parse(input: &str) -> IResult<&str, Vec<Line>> {
-    separated_list1(line_ending, parse_line).parse(input)
-}
-
-fn parse_line(input: &str) -> IResult<&str, Line> {
-    map(
-        separated_pair(parse_point, tag(" -> "), parse_point),
-        |(p1, p2)| Line::new(p1, p2),
-    )
-    .parse(input)
+fn read(command: Command) -> Result<Vec<Line>> {
+    Ok(Day5::parse(&command.source()?.read()?)?)
 }
 
-fn parse_point(input: &str) -> IResult<&str, (usize, usize)> {
-    map(
-        separated_pair(complete::u32, complete::char(','), complete::u32),
-        |(x, y)| (x.to_usize(), y.to_usize()),
-    )
-    .parse(input)
-}
-
-fn read_input(data: &str) -> Result<Vec<Line>> {
-    let (_, input) = parse(data).expect("Parse failure");
+fn render(mut command: Command) -> Result<()> {
+    let highlight = match command.option::<usize>("--highlight")? {
+        Some(row) => Some(row.checked_sub(1).context("Input rows start at 1")?),
+        None => None,
+    };
+    let orientations = command
+        .option("--orientations")?
+        .unwrap_or(Orientations::WITH_DIAGONALS);
+    let scale = command.option("--scale")?.unwrap_or(1);
+    let target = command.argument::<String>("target")?;
 
-    Ok(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() -> Result<()> {
-        let lines = read_input(DATA)?;
-
-        let count = part_one(&lines);
-
-        assert_eq!(count, 7142);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_part_two() -> Result<()> {
-        let lines = read_input(DATA)?;
-
-        let count = part_two(&lines);
-
-        assert_eq!(count, 20012);
+    let map = VentMap::new(&read(command)?, orientations, highlight)?;
+    let image = match target.as_str() {
+        "ascii" => {
+            print!("{}", map.ascii());
+            return Ok(());
+        }
+        file if file.ends_with(".pgm") => map.pgm(scale)?,
+        file if file.ends_with(".png") => map.png(scale)?,
+        _ => return Err(Error::msg(RENDER_USAGE)),
+    };
 
-        Ok(())
-    }
+    fs::write(&target, image).with_context(|| format!("Failed to write {target}"))
 }
@@ -0,0 +1,252 @@
+use crate::{Line, Orientations, Point};
+use anyhow::{Context, Error, Result};
+use grid::Grid;
+
+/// The most cells a map may have, so a render of far apart lines fails instead of running out of
+/// memory.
+pub const MAX_CELLS: usize = 1 << 26;
+
+/// The most pixels an image of a map may have, however much it is scaled up.
+pub const MAX_PIXELS: usize = 1 << 26;
+
+/// The lines drawn onto a grid cropped to their bounding box, with how many cover every point.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VentMap {
+    /// The point of the top left cell.
+    origin: Point,
+    counts: Grid<u32>,
+    highlighted: Grid<bool>,
+}
+
+impl VentMap {
+    /// Draws the lines with one of `orientations`, highlighting the points of the line on the
+    /// 0-based input row `highlight`, which has to be one of those drawn.
+    pub fn new(
+        lines: &[Line],
+        orientations: Orientations,
+        highlight: Option<usize>,
+    ) -> Result<Self> {
+        if let Some(row) = highlight {
+            let line = lines
+                .get(row)
+                .with_context(|| format!("There is no line on row {} to highlight", row + 1))?;
+            if !orientations.contains(line.orientation()) {
+                return Err(Error::msg(format!(
+                    "The line on row {} is {:?}, which isn't drawn",
+                    row + 1,
+                    line.orientation()
+                )));
+            }
+        }
+        let drawn = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| orientations.contains(line.orientation()))
+            .collect::<Vec<(usize, &Line)>>();
+        let ends = || drawn.iter().flat_map(|(_, line)| [line.from, line.to]);
+        let (Some(left), Some(right), Some(top), Some(bottom)) = (
+            ends().map(|(x, _)| x).min(),
+            ends().map(|(x, _)| x).max(),
+            ends().map(|(_, y)| y).min(),
+            ends().map(|(_, y)| y).max(),
+        ) else {
+            return Err(Error::msg("There are no lines to draw"));
+        };
+
+        let width = usize::try_from(right.abs_diff(left) + 1).ok();
+        let height = usize::try_from(bottom.abs_diff(top) + 1).ok();
+        let (width, height) = width
+            .zip(height)
+            .filter(|(width, height)| width.checked_mul(*height).is_some_and(|c| c <= MAX_CELLS))
+            .with_context(|| {
+                format!("The lines span {left},{top} to {right},{bottom}, too far to draw")
+            })?;
+
+        let mut counts = Grid::filled(width, height, 0);
+        let mut highlighted = Grid::filled(width, height, false);
+        let cell = |(x, y): Point| ((x - left) as usize, (y - top) as usize);
+        for (row, line) in drawn {
+            for point in line.points() {
+                counts[cell(point)] += 1;
+                if highlight == Some(row) {
+                    highlighted[cell(point)] = true;
+                }
+            }
+        }
+
+        Ok(VentMap {
+            origin: (left, top),
+            counts,
+            highlighted,
+        })
+    }
+
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    /// How many lines cover every cell.
+    pub fn counts(&self) -> &Grid<u32> {
+        &self.counts
+    }
+
+    /// The map as in the puzzle, a digit for every covered cell and `.` elsewhere, with `+` for ten
+    /// or more lines. Highlighted cells are shown in reverse video.
+    pub fn ascii(&self) -> String {
+        let mut ascii = String::new();
+        for (row, highlighted) in self.counts.rows().zip(self.highlighted.rows()) {
+            for (count, highlighted) in row.iter().zip(highlighted) {
+                let cell = match count {
+                    0 => '.',
+                    1..=9 => char::from_digit(*count, 10).unwrap_or('?'),
+                    _ => '+',
+                };
+                if *highlighted {
+                    ascii.push_str(&format!("\x1b[7m{cell}\x1b[0m"));
+                } else {
+                    ascii.push(cell);
+                }
+            }
+            ascii.push('\n');
+        }
+
+        ascii
+    }
+
+    /// A binary greyscale PGM of the map, `scale` pixels to a cell, that gets lighter the more
+    /// lines cover a cell. Highlighted cells are white, and the others stay below three quarters.
+    pub fn pgm(&self, scale: usize) -> Result<Vec<u8>> {
+        let (width, height) = self.image_size(scale)?;
+        let most = self.most();
+        let pixels = self.pixels(scale, |count, highlighted| {
+            [if highlighted {
+                255
+            } else {
+                shade(count, most, 191)
+            }]
+        });
+        let header = format!("P5\n{width} {height}\n255\n");
+
+        Ok([header.into_bytes(), pixels].concat())
+    }
+
+    /// A PNG of the map, `scale` pixels to a cell, shaded like the PGM but with the highlighted
+    /// cells in red.
+    pub fn png(&self, scale: usize) -> Result<Vec<u8>> {
+        let (width, height) = self.image_size(scale)?;
+        let most = self.most();
+        let pixels = self.pixels(scale, |count, highlighted| {
+            let shade = shade(count, most, 255);
+            if highlighted {
+                [255, shade / 2, shade / 2]
+            } else {
+                [shade; 3]
+            }
+        });
+
+        let mut png = Vec::new();
+        let mut encoder =
+            png::Encoder::new(&mut png, u32::try_from(width)?, u32::try_from(height)?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&pixels)?;
+        writer.finish()?;
+
+        Ok(png)
+    }
+
+    /// The width and height of an image `scale` pixels to a cell, failing for a scale of zero or
+    /// one making more than [`MAX_PIXELS`] pixels.
+    fn image_size(&self, scale: usize) -> Result<(usize, usize)> {
+        if scale == 0 {
+            return Err(Error::msg("The scale must be at least one pixel to a cell"));
+        }
+        let width = self.counts.width().checked_mul(scale);
+        let height = self.counts.height().checked_mul(scale);
+        width
+            .zip(height)
+            .filter(|(width, height)| width.checked_mul(*height).is_some_and(|p| p <= MAX_PIXELS))
+            .with_context(|| {
+                format!("A scale of {scale} makes an image of more than {MAX_PIXELS} pixels")
+            })
+    }
+
+    /// The most lines covering any cell.
+    fn most(&self) -> u32 {
+        self.counts.values().max().copied().unwrap_or_default()
+    }
+
+    /// The bytes of every pixel, row by row, with every cell blown up to a `scale` wide square.
+    fn pixels<const N: usize>(
+        &self,
+        scale: usize,
+        pixel: impl Fn(u32, bool) -> [u8; N],
+    ) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.counts.len() * scale * scale * N);
+        for (row, highlighted) in self.counts.rows().zip(self.highlighted.rows()) {
+            let line = row
+                .iter()
+                .zip(highlighted)
+                .flat_map(|(count, highlighted)| pixel(*count, *highlighted).repeat(scale))
+                .collect::<Vec<u8>>();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        pixels
+    }
+}
+
+/// The shade of a cell between black for no lines and `brightest` for the `most` lines of all.
+fn shade(count: u32, most: u32, brightest: u8) -> u8 {
+    (count as u64 * brightest as u64 / most.max(1) as u64) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cropped_ascii() -> Result<()> {
+        let lines = [
+            Line::new((-1, 5), (2, 5)),
+            Line::new((1, 4), (1, 6)),
+            Line::new((0, 4), (2, 6)),
+        ];
+
+        let map = VentMap::new(&lines, Orientations::AXIS_ALIGNED, Some(1))?;
+        assert_eq!(map.origin(), (-1, 4));
+        assert_eq!(
+            map.ascii(),
+            "..\x1b[7m1\x1b[0m.\n11\x1b[7m2\x1b[0m1\n..\x1b[7m1\x1b[0m.\n"
+        );
+
+        let map = VentMap::new(&lines, Orientations::WITH_DIAGONALS, None)?;
+        assert_eq!(map.ascii(), ".11.\n1131\n..11\n");
+
+        // the diagonal isn't drawn, and there's no fourth line
+        assert!(VentMap::new(&lines, Orientations::AXIS_ALIGNED, Some(2)).is_err());
+        assert!(VentMap::new(&lines, Orientations::WITH_DIAGONALS, Some(3)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_images() -> Result<()> {
+        let map = VentMap::new(&[Line::new((0, 0), (1, 0))], Orientations::ALL, Some(0))?;
+
+        assert_eq!(
+            map.pgm(2)?,
+            b"P5\n4 2\n255\n\xff\xff\xff\xff\xff\xff\xff\xff"
+        );
+        assert!(map.png(3)?.starts_with(b"\x89PNG\r\n\x1a\n"));
+        assert!(map.pgm(0).is_err());
+        assert!(map.png(1 << 13).is_err());
+        assert!(map.png(usize::MAX).is_err());
+        assert!(VentMap::new(&[Line::new((0, 0), (1 << 40, 0))], Orientations::ALL, None).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,376 @@
+use anyhow::Error;
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+/// A point of the ocean floor as `(x, y)`.
+pub type Point = (i64, i64);
+
+/// How far from the origin coordinates may lie, so crossings can be solved exactly in `i128`.
+pub const LIMIT: i64 = 1 << 61;
+
+/// The direction of a line of vents.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Orientation {
+    /// Along the x axis, which includes a line of a single point.
+    Horizontal,
+    Vertical,
+    /// At 45 degrees.
+    Diagonal,
+    /// At any other slope, going through every lattice point between its ends.
+    Sloped,
+}
+
+/// The orientations of the lines that are counted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Orientations {
+    pub horizontal: bool,
+    pub vertical: bool,
+    pub diagonal: bool,
+    pub sloped: bool,
+}
+
+impl Orientations {
+    /// The lines of part one.
+    pub const AXIS_ALIGNED: Orientations = Orientations {
+        horizontal: true,
+        vertical: true,
+        diagonal: false,
+        sloped: false,
+    };
+
+    /// The lines of part two.
+    pub const WITH_DIAGONALS: Orientations = Orientations {
+        diagonal: true,
+        ..Orientations::AXIS_ALIGNED
+    };
+
+    pub const ALL: Orientations = Orientations {
+        sloped: true,
+        ..Orientations::WITH_DIAGONALS
+    };
+
+    pub fn contains(&self, orientation: Orientation) -> bool {
+        match orientation {
+            Orientation::Horizontal => self.horizontal,
+            Orientation::Vertical => self.vertical,
+            Orientation::Diagonal => self.diagonal,
+            Orientation::Sloped => self.sloped,
+        }
+    }
+}
+
+impl FromStr for Orientations {
+    type Err = Error;
+
+    /// Parses a comma separated list of orientations such as `horizontal,vertical,diagonal`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut orientations = Orientations {
+            horizontal: false,
+            vertical: false,
+            diagonal: false,
+            sloped: false,
+        };
+        for orientation in s.split(',').map(str::trim) {
+            match orientation {
+                "horizontal" => orientations.horizontal = true,
+                "vertical" => orientations.vertical = true,
+                "diagonal" => orientations.diagonal = true,
+                "sloped" => orientations.sloped = true,
+                orientation => {
+                    return Err(Error::msg(format!(
+                        "Unknown orientation {orientation:?}, expected horizontal, vertical, \
+                         diagonal or sloped"
+                    )));
+                }
+            }
+        }
+
+        Ok(orientations)
+    }
+}
+
+/// A line of vents between two points, both included.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Line {
+    pub from: Point,
+    pub to: Point,
+}
+
+impl Line {
+    pub fn new(from: Point, to: Point) -> Self {
+        Line { from, to }
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        let (dx, dy) = (
+            self.to.0.abs_diff(self.from.0),
+            self.to.1.abs_diff(self.from.1),
+        );
+        match (dx, dy) {
+            (_, 0) => Orientation::Horizontal,
+            (0, _) => Orientation::Vertical,
+            (dx, dy) if dx == dy => Orientation::Diagonal,
+            _ => Orientation::Sloped,
+        }
+    }
+
+    /// Every lattice point of the line, from its start to its end.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<> {
+        let segment = Segment::new(self);
+        let (start, step) = if segment.start == widen(self.from) {
+            (segment.start, segment.step)
+        } else {
+            (widen(self.from), (-segment.step.0, -segment.step.1))
+        };
+
+        (0..=segment.steps).map(move |k| {
+            let point = (start.0 + k * step.0, start.1 + k * step.1);
+            (point.0 as i64, point.1 as i64)
+        })
+    }
+}
+
+type Wide = (i128, i128);
+
+fn widen((x, y): Point) -> Wide {
+    (x as i128, y as i128)
+}
+
+fn cross(a: Wide, b: Wide) -> i128 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The infinite line a segment lies on: its primitive direction and the cross product of that
+/// direction with any of its points, which is the same all along it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Carrier {
+    step: Wide,
+    offset: i128,
+}
+
+impl Carrier {
+    /// Where a point of the carrier lies along it, counted in steps.
+    fn position(&self, point: Wide) -> i128 {
+        if self.step.0 != 0 {
+            point.0.div_euclid(self.step.0)
+        } else {
+            point.1
+        }
+    }
+}
+
+/// A line walked in the direction of its carrier, one lattice point per step.
+#[derive(Clone, Copy, Debug)]
+struct Segment {
+    start: Wide,
+    step: Wide,
+    steps: i128,
+}
+
+impl Segment {
+    fn new(line: &Line) -> Self {
+        let (from, to) = (widen(line.from), widen(line.to));
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        let steps = gcd(dx.abs(), dy.abs());
+        if steps == 0 {
+            return Segment {
+                start: from,
+                step: (1, 0),
+                steps,
+            };
+        }
+
+        let step = (dx / steps, dy / steps);
+        if step.0 < 0 || (step.0 == 0 && step.1 < 0) {
+            Segment {
+                start: to,
+                step: (-step.0, -step.1),
+                steps,
+            }
+        } else {
+            Segment {
+                start: from,
+                step,
+                steps,
+            }
+        }
+    }
+
+    fn carrier(&self) -> Carrier {
+        Carrier {
+            step: self.step,
+            offset: cross(self.step, self.start),
+        }
+    }
+
+    /// The first and last position of the segment along its carrier.
+    fn span(&self) -> (i128, i128) {
+        let start = self.carrier().position(self.start);
+        (start, start + self.steps)
+    }
+
+    fn min_x(&self) -> i128 {
+        self.start.0
+    }
+
+    fn max_x(&self) -> i128 {
+        self.start.0 + self.steps * self.step.0
+    }
+
+    /// The lattice point two segments on different carriers share, if any.
+    fn crossing(&self, other: &Segment) -> Option<Wide> {
+        let denominator = cross(self.step, other.step);
+        if denominator == 0 {
+            return None;
+        }
+        let between = (other.start.0 - self.start.0, other.start.1 - self.start.1);
+        let (s, u) = (cross(between, other.step), cross(between, self.step));
+        if s % denominator != 0 || u % denominator != 0 {
+            return None;
+        }
+        let (s, u) = (s / denominator, u / denominator);
+
+        ((0..=self.steps).contains(&s) && (0..=other.steps).contains(&u)).then(|| {
+            (
+                self.start.0 + s * self.step.0,
+                self.start.1 + s * self.step.1,
+            )
+        })
+    }
+}
+
+/// The stretches of a carrier, as inclusive spans of positions, that two or more segments cover.
+fn overlapping_spans(spans: Vec<(i128, i128)>) -> Vec<(i128, i128)> {
+    let mut events = spans
+        .into_iter()
+        .flat_map(|(first, last)| [(first, 1), (last + 1, -1)])
+        .collect::<Vec<(i128, i32)>>();
+    events.sort_unstable();
+
+    let mut overlaps: Vec<(i128, i128)> = Vec::new();
+    let mut depth = 0;
+    let mut opened = 0;
+    for (position, change) in events {
+        let was_overlapping = depth >= 2;
+        depth += change;
+        match (was_overlapping, depth >= 2) {
+            (false, true) => opened = position,
+            (true, false) if position > opened => match overlaps.last_mut() {
+                Some((_, last)) if *last + 1 == opened => *last = position - 1,
+                _ => overlaps.push((opened, position - 1)),
+            },
+            _ => {}
+        }
+    }
+
+    overlaps
+}
+
+/// The number of points where at least two of the lines with one of `orientations` overlap.
+///
+/// Collinear lines are merged as spans along the line they share, and other lines are only
+/// intersected in pairs found by sweeping across x, so no line is walked point by point.
+pub fn overlaps(lines: &[Line], orientations: Orientations) -> u128 {
+    let mut segments = lines
+        .iter()
+        .filter(|line| orientations.contains(line.orientation()))
+        .map(Segment::new)
+        .collect::<Vec<Segment>>();
+
+    let mut carriers: HashMap<Carrier, Vec<(i128, i128)>> = HashMap::new();
+    for segment in &segments {
+        carriers
+            .entry(segment.carrier())
+            .or_default()
+            .push(segment.span());
+    }
+    let carriers = carriers
+        .into_iter()
+        .map(|(carrier, spans)| (carrier, overlapping_spans(spans)))
+        .collect::<HashMap<Carrier, Vec<(i128, i128)>>>();
+
+    // segments are sorted by where they start along x, so only those starting before another
+    // ends can cross it
+    segments.sort_unstable_by_key(Segment::min_x);
+    let mut crossings: HashMap<Wide, HashSet<Carrier>> = HashMap::new();
+    for (index, segment) in segments.iter().enumerate() {
+        for other in segments[index + 1..]
+            .iter()
+            .take_while(|other| other.min_x() <= segment.max_x())
+        {
+            if let Some(point) = segment.crossing(other) {
+                let carriers = crossings.entry(point).or_default();
+                carriers.insert(segment.carrier());
+                carriers.insert(other.carrier());
+            }
+        }
+    }
+
+    let along = carriers
+        .values()
+        .flatten()
+        .map(|(first, last)| last - first + 1)
+        .sum::<i128>();
+    // a crossing counts once, minus every time it was already counted as part of a span
+    let at_crossings = crossings
+        .iter()
+        .map(|(point, through)| {
+            let counted = through
+                .iter()
+                .filter(|carrier| {
+                    let position = carrier.position(*point);
+                    carriers[*carrier]
+                        .iter()
+                        .any(|(first, last)| (*first..=*last).contains(&position))
+                })
+                .count() as i128;
+            1 - counted
+        })
+        .sum::<i128>();
+
+    (along + at_crossings) as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlapping_spans() {
+        let spans = vec![(0, 10), (5, 15), (12, 20), (30, 31), (31, 40), (20, 20)];
+
+        assert_eq!(
+            overlapping_spans(spans),
+            vec![(5, 10), (12, 15), (20, 20), (31, 31)]
+        );
+    }
+
+    #[test]
+    fn test_sloped_lines() {
+        let lines = [
+            Line::new((0, 0), (6, 3)),
+            Line::new((0, 3), (6, 0)),
+            Line::new((-2, -1), (2, 1)),
+            Line::new((2, 0), (2, 5)),
+        ];
+
+        // (0, 0) and (2, 1) shared by the collinear lines, where the vertical one crosses too, and
+        // (2, 2) where it crosses the falling one
+        assert_eq!(overlaps(&lines, Orientations::ALL), 3);
+        assert_eq!(overlaps(&lines, Orientations::AXIS_ALIGNED), 0);
+        assert_eq!(
+            Line::new((0, 0), (6, 3)).points().collect::<Vec<_>>(),
+            vec![(0, 0), (2, 1), (4, 2), (6, 3)]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+mod population;
+
+pub use population::{Arithmetic, Exact, Modulo, Population, Timers, MAX_TIMER};
+
+use anyhow::Result;
+use aoc_core::{
+    parse::{finish, IResult},
+    ParseError, Solution,
+};
+use nom::{character::complete, multi::separated_list1, Parser};
+use num_bigint::BigUint;
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Vec<u8>;
+    type Output1 = BigUint;
+    type Output2 = BigUint;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn part_one(input: &[u8]) -> Result<BigUint> {
+    Ok(Population::new(input, Timers::default())?.after(80, &Exact))
+}
+
+fn part_two(input: &[u8]) -> Result<BigUint> {
+    Ok(Population::new(input, Timers::default())?.after(256, &Exact))
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<u8>> {
+    separated_list1(complete::char(','), complete::u8).parse(input)
+}
+
+fn read_input(data: impl AsRef<str>) -> Result<Vec<u8>, ParseError> {
+    let data = data.as_ref();
+
+    finish(Day6::DAY, data, parse(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_one(&input)?;
+
+        assert_eq!(count, BigUint::from(5934u32));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_two(&input)?;
+
+        assert_eq!(count, BigUint::from(26984457539u64));
+
+        Ok(())
+    }
+}
@@ -1,88 +1,57 @@
-use anyhow::Result;
-use nom::{character::complete, multi::separated_list1, IResult, Parser};
-use std::collections::HashMap;
+use anyhow::{Context, Error, Result};
+use aoc_core::{args::Command, Solution};
+use day6::{Arithmetic, Day6, Exact, Modulo, Population, Timers};
+use std::fmt::Display;
 
-const DATA: &str = include_str!("input.txt");
+const USAGE: &str =
+    "Usage: day6 --days <DAYS,...> [--modulo MODULUS] [--timers RESET,SPAWN] [PATH]";
 
+/// Solves the puzzle, or with `--days <DAYS,...>` prints how many fish the school in PATH (stdin
+/// when left out or `-`) grows to after each of DAYS, optionally modulo MODULUS and with other
+/// timers than 6 and 8.
 fn main() -> Result<()> {
-    let input = read_input(DATA)?;
-
-    let (took, result) = took::took(|| part_one(input.clone()));
-    println!("Result part one: {result}");
-    println!("Time spent: {took}");
-
-    let (took, result) = took::took(|| part_two(input));
-    println!("Result part two: {result}");
-    println!("Time spent: {took}");
-
-    Ok(())
-}
-
-fn part_one(input: HashMap<i8, i64>) -> i64 {
-    algorithm(input, 80)
-}
-
-fn part_two(input: HashMap<i8, i64>) -> i64 {
-    algorithm(input, 256)
+    match Command::from_env("--days", USAGE) {
+        Some(command) => query(command),
+        None => aoc_core::run::<Day6>(),
+    }
 }
 
-fn algorithm(mut input: HashMap<i8, i64>, days: i16) -> i64 {
-    for _ in 0..days {
-        let mut new_borns = 0;
-        let mut reset_fish = 0;
-        for fish in 0..=8 {
-            let number = *input.get(&fish).unwrap_or(&0);
-            if fish == 0 {
-                new_borns = number;
-                reset_fish = number;
-            } else {
-                input.insert(fish - 1, number);
+fn query(mut command: Command) -> Result<()> {
+    let modulus = match command.option::<u64>("--modulo")? {
+        Some(0) => return Err(Error::msg("The modulus must not be zero")),
+        modulus => modulus,
+    };
+    let timers = match command.option::<String>("--timers")? {
+        Some(timers) => {
+            let (reset, spawn) = timers.split_once(',').context(USAGE)?;
+            Timers {
+                reset: reset.trim().parse()?,
+                spawn: spawn.trim().parse()?,
             }
         }
-        input.entry(6).and_modify(|x| *x += reset_fish);
-        input.insert(8, new_borns);
+        None => Timers::default(),
+    };
+    let days = command.argument::<String>("days")?;
+    let days = days
+        .split(',')
+        .map(|days| days.trim().parse::<u64>())
+        .collect::<Result<Vec<u64>, _>>()
+        .with_context(|| format!("Expected day counts such as 80,256, got {days:?}"))?;
+
+    let population = Population::new(&Day6::parse(&command.source()?.read()?)?, timers)?;
+    match modulus {
+        Some(modulus) => print(&population, &days, &Modulo(modulus)),
+        None => print(&population, &days, &Exact),
     }
 
-    input.values().sum()
-}
-
-fn parse(input: &str) -> IResult<&str, Vec<i8>> {
-    separated_list1(complete::char(','), complete::i8).parse(input)
-}
-
-fn read_input(data: &str) -> Result<HashMap<i8, i64>> {
-    let (_, input) = parse(data).expect("Parse failure");
-    let mut map: HashMap<i8, i64> = HashMap::new();
-    for fish in input {
-        *map.entry(fish).or_insert(0) += 1;
-    }
-
-    Ok(map)
+    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() -> Result<()> {
-        let input = read_input(DATA)?;
-
-        let count = part_one(input);
-
-        assert_eq!(count, 362639);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_part_two() -> Result<()> {
-        let input = read_input(DATA)?;
-
-        let count = part_two(input);
-
-        assert_eq!(count, 1639854996917);
-
-        Ok(())
+fn print<A: Arithmetic>(population: &Population, days: &[u64], arithmetic: &A)
+where
+    A::Value: Display,
+{
+    for (days, total) in days.iter().zip(population.after_each(days, arithmetic)) {
+        println!("{days:>8}: {total}");
     }
 }
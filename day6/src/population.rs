@@ -0,0 +1,293 @@
+use anyhow::{Error, Result};
+use num_bigint::BigUint;
+
+/// The longest timer a fish may have, keeping the transition matrix to 100 by 100 counts.
+pub const MAX_TIMER: usize = 99;
+
+/// The timers of a lanternfish: the one a fish restarts from after spawning, and the one a newborn
+/// fish starts from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Timers {
+    pub reset: usize,
+    pub spawn: usize,
+}
+
+impl Default for Timers {
+    fn default() -> Self {
+        Timers { reset: 6, spawn: 8 }
+    }
+}
+
+impl Timers {
+    /// How many timer values a fish can have, from 0 up to the larger timer.
+    fn states(&self) -> usize {
+        self.reset.max(self.spawn) + 1
+    }
+}
+
+/// The numbers fish are counted in.
+pub trait Arithmetic {
+    type Value: Clone;
+
+    fn zero(&self) -> Self::Value;
+
+    /// The value of a count of fish.
+    fn value(&self, value: u64) -> Self::Value;
+
+    fn add(&self, a: &Self::Value, b: &Self::Value) -> Self::Value;
+
+    fn mul(&self, a: &Self::Value, b: &Self::Value) -> Self::Value;
+}
+
+/// Counts every fish, however many there are.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Exact;
+
+impl Arithmetic for Exact {
+    type Value = BigUint;
+
+    fn zero(&self) -> BigUint {
+        BigUint::ZERO
+    }
+
+    fn value(&self, value: u64) -> BigUint {
+        BigUint::from(value)
+    }
+
+    fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a + b
+    }
+
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a * b
+    }
+}
+
+/// Counts the fish modulo a number, which must not be zero.
+#[derive(Clone, Copy, Debug)]
+pub struct Modulo(pub u64);
+
+impl Arithmetic for Modulo {
+    type Value = u64;
+
+    fn zero(&self) -> u64 {
+        0
+    }
+
+    fn value(&self, value: u64) -> u64 {
+        value % self.0
+    }
+
+    fn add(&self, a: &u64, b: &u64) -> u64 {
+        ((*a as u128 + *b as u128) % self.0 as u128) as u64
+    }
+
+    fn mul(&self, a: &u64, b: &u64) -> u64 {
+        (*a as u128 * *b as u128 % self.0 as u128) as u64
+    }
+}
+
+/// A square matrix over the values of an arithmetic, stored row by row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Matrix<V> {
+    size: usize,
+    cells: Vec<V>,
+}
+
+impl<V: Clone> Matrix<V> {
+    /// How many fish of every timer one fish of every timer turns into after a day: column `from`
+    /// holds the fish a fish with timer `from` becomes.
+    fn transition<A: Arithmetic<Value = V>>(timers: Timers, arithmetic: &A) -> Self {
+        let size = timers.states();
+        let mut counts = vec![0; size * size];
+        for from in 1..size {
+            counts[(from - 1) * size + from] += 1;
+        }
+        counts[timers.reset * size] += 1;
+        counts[timers.spawn * size] += 1;
+
+        Matrix {
+            size,
+            cells: counts.into_iter().map(|c| arithmetic.value(c)).collect(),
+        }
+    }
+
+    fn mul<A: Arithmetic<Value = V>>(&self, other: &Self, arithmetic: &A) -> Self {
+        let size = self.size;
+        let cells = (0..size * size)
+            .map(|cell| {
+                let (row, column) = (cell / size, cell % size);
+                (0..size).fold(arithmetic.zero(), |sum, k| {
+                    let product = arithmetic
+                        .mul(&self.cells[row * size + k], &other.cells[k * size + column]);
+                    arithmetic.add(&sum, &product)
+                })
+            })
+            .collect();
+
+        Matrix { size, cells }
+    }
+
+    fn apply<A: Arithmetic<Value = V>>(&self, counts: &[V], arithmetic: &A) -> Vec<V> {
+        self.cells
+            .chunks(self.size)
+            .map(|row| {
+                row.iter()
+                    .zip(counts)
+                    .fold(arithmetic.zero(), |sum, (a, b)| {
+                        arithmetic.add(&sum, &arithmetic.mul(a, b))
+                    })
+            })
+            .collect()
+    }
+}
+
+/// A school of lanternfish, counted by timer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Population {
+    counts: Vec<u64>,
+    timers: Timers,
+}
+
+impl Population {
+    pub fn new(fish: &[u8], timers: Timers) -> Result<Self> {
+        if timers.reset.max(timers.spawn) > MAX_TIMER {
+            return Err(Error::msg(format!(
+                "The timers {} and {} must be at most {MAX_TIMER}",
+                timers.reset, timers.spawn
+            )));
+        }
+        let mut counts = vec![0; timers.states()];
+        for timer in fish {
+            let count = counts.get_mut(*timer as usize).ok_or_else(|| {
+                Error::msg(format!(
+                    "A fish with timer {timer} is past the longest timer, {}",
+                    timers.states() - 1
+                ))
+            })?;
+            *count += 1;
+        }
+
+        Ok(Population { counts, timers })
+    }
+
+    pub fn timers(&self) -> Timers {
+        self.timers
+    }
+
+    /// The number of fish after `days` days.
+    pub fn after<A: Arithmetic>(&self, days: u64, arithmetic: &A) -> A::Value {
+        self.after_each(&[days], arithmetic).remove(0)
+    }
+
+    /// The number of fish after each of `days`, in the same order.
+    ///
+    /// The school is moved from one day count to the next in increasing order, each time by the
+    /// transition matrix raised to the gap, built from the squares of the matrix.
+    pub fn after_each<A: Arithmetic>(&self, days: &[u64], arithmetic: &A) -> Vec<A::Value> {
+        let mut order = (0..days.len()).collect::<Vec<usize>>();
+        order.sort_unstable_by_key(|query| days[*query]);
+
+        let mut squares = vec![Matrix::transition(self.timers, arithmetic)];
+        let mut counts = self
+            .counts
+            .iter()
+            .map(|count| arithmetic.value(*count))
+            .collect::<Vec<A::Value>>();
+        let mut day = 0;
+        let mut totals = vec![arithmetic.zero(); days.len()];
+
+        for query in order {
+            let mut gap = days[query] - day;
+            let mut bit = 0;
+            while gap > 0 {
+                if bit == squares.len() {
+                    let square = &squares[bit - 1];
+                    squares.push(square.mul(square, arithmetic));
+                }
+                if gap & 1 == 1 {
+                    counts = squares[bit].apply(&counts, arithmetic);
+                }
+                gap >>= 1;
+                bit += 1;
+            }
+            day = days[query];
+            totals[query] = counts
+                .iter()
+                .fold(arithmetic.zero(), |sum, count| arithmetic.add(&sum, count));
+        }
+
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: [u8; 5] = [3, 4, 3, 1, 2];
+
+    /// The number of fish after `days`, spawning them one day at a time.
+    fn simulate(fish: &[u8], days: u64, timers: Timers) -> u64 {
+        let mut counts = vec![0u64; timers.states()];
+        fish.iter().for_each(|timer| counts[*timer as usize] += 1);
+        for _ in 0..days {
+            let spawning = counts.remove(0);
+            counts.push(0);
+            counts[timers.reset] += spawning;
+            counts[timers.spawn] += spawning;
+        }
+
+        counts.iter().sum()
+    }
+
+    #[test]
+    fn test_queries() -> Result<()> {
+        let population = Population::new(&SAMPLE, Timers::default())?;
+
+        let totals = population.after_each(&[80, 18, 256, 0, 18], &Exact);
+
+        assert_eq!(totals, [5934u64, 26, 26984457539, 5, 26].map(BigUint::from));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_timers() -> Result<()> {
+        for (timers, fish) in [
+            (Timers { reset: 2, spawn: 5 }, &[0, 1, 1, 5][..]),
+            (Timers { reset: 4, spawn: 1 }, &[0, 1, 4]),
+            (Timers { reset: 0, spawn: 0 }, &[0, 0]),
+        ] {
+            let population = Population::new(fish, timers)?;
+            for days in [0, 1, 7, 30] {
+                let expected = simulate(fish, days, timers);
+                assert_eq!(population.after(days, &Exact), BigUint::from(expected));
+            }
+        }
+        assert!(Population::new(&[9], Timers::default()).is_err());
+        let huge = Timers {
+            reset: 6,
+            spawn: usize::MAX,
+        };
+        assert!(Population::new(&[0], huge).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_modulo() -> Result<()> {
+        let population = Population::new(&SAMPLE, Timers::default())?;
+        let modulus = 1_000_000_007;
+
+        let exact = population.after(10_000, &Exact);
+
+        assert_eq!(
+            BigUint::from(population.after(10_000, &Modulo(modulus))),
+            exact % modulus
+        );
+        assert_eq!(population.after(u64::MAX, &Modulo(1)), 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::RangeInclusive,
+};
+
+/// The fuel a crab burns to move a distance.
+///
+/// The fuel must not go down as the distance grows, and must grow at least as fast with every
+/// further step (be convex), so the total fuel of the crabs has a single valley of optimal
+/// positions.
+pub trait FuelCost {
+    fn fuel(&self, distance: u64) -> u128;
+
+    /// A position at or next to an optimal one, if it's known without searching.
+    fn hint(&self, _crabs: &Crabs) -> Option<i64> {
+        None
+    }
+}
+
+/// Every step costs one unit of fuel; the weighted median is optimal.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Linear;
+
+impl FuelCost for Linear {
+    fn fuel(&self, distance: u64) -> u128 {
+        distance as u128
+    }
+
+    fn hint(&self, crabs: &Crabs) -> Option<i64> {
+        let half = crabs.weight().div_ceil(2);
+        let mut seen = 0;
+        crabs.crabs.iter().find_map(|(position, weight)| {
+            seen += *weight as u64;
+            (seen >= half).then_some(*position)
+        })
+    }
+}
+
+/// Every step costs one more unit than the last; the optimum lies within half a step of the
+/// weighted mean.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Triangular;
+
+impl FuelCost for Triangular {
+    fn fuel(&self, distance: u64) -> u128 {
+        let distance = distance as u128;
+        distance * (distance + 1) / 2
+    }
+
+    fn hint(&self, crabs: &Crabs) -> Option<i64> {
+        let weight = crabs.weight() as i128;
+        let sum = crabs
+            .crabs
+            .iter()
+            .map(|(position, w)| *position as i128 * *w as i128)
+            .sum::<i128>();
+        (weight > 0).then(|| (sum * 2 + weight).div_euclid(weight * 2) as i64)
+    }
+}
+
+/// Crabs by position, each position with the number of crabs on it.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Crabs {
+    crabs: Vec<(i64, u32)>,
+}
+
+impl Crabs {
+    /// A crab on every position, any number of them on the same one.
+    pub fn new(positions: &[i32]) -> Self {
+        Crabs::weighted(positions.iter().map(|position| (*position, 1)))
+    }
+
+    /// Crabs given as positions with how many crabs are on them.
+    pub fn weighted(crabs: impl IntoIterator<Item = (i32, u32)>) -> Self {
+        let mut crabs = crabs
+            .into_iter()
+            .filter(|(_, weight)| *weight > 0)
+            .map(|(position, weight)| (position as i64, weight))
+            .collect::<Vec<(i64, u32)>>();
+        crabs.sort_unstable();
+        crabs.dedup_by(|(position, weight), (kept, total)| {
+            let same = position == kept;
+            if same {
+                *total += *weight;
+            }
+            same
+        });
+
+        Crabs { crabs }
+    }
+
+    /// The number of crabs.
+    pub fn weight(&self) -> u64 {
+        self.crabs.iter().map(|(_, weight)| *weight as u64).sum()
+    }
+
+    /// The fuel all crabs burn moving to `target`.
+    pub fn fuel(&self, target: i64, cost: &impl FuelCost) -> u128 {
+        self.crabs
+            .iter()
+            .map(|(position, weight)| cost.fuel(position.abs_diff(target)) * *weight as u128)
+            .sum()
+    }
+}
+
+/// The cheapest positions for the crabs to align on, the leftmost one first, and the fuel it
+/// costs; the answer is the fuel.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Alignment {
+    pub position: i64,
+    /// The rightmost position that is just as cheap.
+    pub last_position: i64,
+    pub fuel_spent: u128,
+}
+
+impl Alignment {
+    /// Every optimal position.
+    pub fn positions(&self) -> RangeInclusive<i64> {
+        self.position..=self.last_position
+    }
+}
+
+impl Display for Alignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.fuel_spent)
+    }
+}
+
+/// The cheapest positions to align the crabs on, or `None` without any crabs.
+///
+/// Starts from the hint of the cost and walks downhill, or without a hint bisects on the slope of
+/// the total fuel, then bisects for the ends of the valley.
+pub fn align(crabs: &Crabs, cost: &impl FuelCost) -> Option<Alignment> {
+    let (first, last) = (crabs.crabs.first()?.0, crabs.crabs.last()?.0);
+    let fuel = |target| crabs.fuel(target, cost);
+
+    let optimum = match cost.hint(crabs) {
+        Some(hint) => {
+            let mut position = hint.clamp(first, last);
+            while position > first && fuel(position - 1) < fuel(position) {
+                position -= 1;
+            }
+            while position < last && fuel(position + 1) < fuel(position) {
+                position += 1;
+            }
+            position
+        }
+        None => bisect(first, last, |position| fuel(position + 1) >= fuel(position)),
+    };
+
+    let fuel_spent = fuel(optimum);
+    let position = bisect(first, optimum, |position| fuel(position) == fuel_spent);
+    let last_position = bisect(optimum, last, |position| fuel(position + 1) > fuel_spent);
+
+    Some(Alignment {
+        position,
+        last_position,
+        fuel_spent,
+    })
+}
+
+/// The first position in `low..=high` where `found` holds, given it holds from there on and at
+/// `high`.
+fn bisect(mut low: i64, mut high: i64, found: impl Fn(i64) -> bool) -> i64 {
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if found(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: [i32; 10] = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+
+    /// A cost without a hint, so the solver has to search.
+    struct Squared;
+
+    impl FuelCost for Squared {
+        fn fuel(&self, distance: u64) -> u128 {
+            distance as u128 * distance as u128
+        }
+    }
+
+    /// The cheapest positions and their fuel, trying every position between the crabs.
+    fn align_naive(crabs: &Crabs, cost: &impl FuelCost) -> (Vec<i64>, u128) {
+        let (first, last) = (crabs.crabs[0].0, crabs.crabs[crabs.crabs.len() - 1].0);
+        let fuel = (first..=last)
+            .map(|target| crabs.fuel(target, cost))
+            .collect::<Vec<u128>>();
+        let best = fuel.iter().min().copied().unwrap_or_default();
+        let positions = (first..=last)
+            .zip(&fuel)
+            .filter(|(_, fuel)| **fuel == best)
+            .map(|(position, _)| position)
+            .collect();
+
+        (positions, best)
+    }
+
+    fn check(crabs: &Crabs) {
+        fn agrees(crabs: &Crabs, cost: &impl FuelCost) {
+            let alignment = align(crabs, cost).unwrap();
+            let (positions, best) = align_naive(crabs, cost);
+            assert_eq!(alignment.positions().collect::<Vec<_>>(), positions);
+            assert_eq!(alignment.fuel_spent, best);
+        }
+
+        agrees(crabs, &Linear);
+        agrees(crabs, &Triangular);
+        agrees(crabs, &Squared);
+    }
+
+    #[test]
+    fn test_matches_naive() {
+        check(&Crabs::new(&SAMPLE));
+        check(&Crabs::new(&[-5]));
+        check(&Crabs::new(&[-7, 3, 3, 12, -1, 40, 41]));
+
+        // a pseudo-random but reproducible spread of weighted crabs
+        let mut state = 12345u64;
+        for _ in 0..50 {
+            let crabs = (0..8).map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                ((state >> 40) as i32 % 60 - 30, (state >> 20) as u32 % 4)
+            });
+            let crabs = Crabs::weighted(crabs.collect::<Vec<_>>());
+            if crabs.weight() > 0 {
+                check(&crabs);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ties() {
+        let alignment = align(&Crabs::new(&[0, 10]), &Linear).unwrap();
+
+        assert_eq!(alignment.positions(), 0..=10);
+        assert_eq!(alignment.fuel_spent, 10);
+        assert_eq!(align(&Crabs::new(&[]), &Linear), None);
+    }
+
+    #[test]
+    fn test_weighted() {
+        let crabs = Crabs::weighted([(0, 3), (10, 1), (0, 1)]);
+
+        assert_eq!(crabs.weight(), 5);
+        assert_eq!(align(&crabs, &Linear).map(|a| a.positions()), Some(0..=0));
+        assert_eq!(align(&crabs, &Triangular).map(|a| a.position), Some(2));
+    }
+}
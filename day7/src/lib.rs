@@ -0,0 +1,97 @@
+mod crabs;
+
+pub use crabs::{align, Alignment, Crabs, FuelCost, Linear, Triangular};
+
+use anyhow::{Error, Result};
+use aoc_core::{
+    parse::{finish, IResult},
+    ParseError, Solution,
+};
+use nom::{character::complete, multi::separated_list1, Parser};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Vec<i32>;
+    type Output1 = Alignment;
+    type Output2 = Alignment;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn part_one(input: &[i32]) -> Result<Alignment> {
+    align(&Crabs::new(input), &Linear).ok_or_else(|| Error::msg("No result found"))
+}
+
+fn part_two(input: &[i32]) -> Result<Alignment> {
+    align(&Crabs::new(input), &Triangular).ok_or_else(|| Error::msg("No result found"))
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<i32>> {
+    separated_list1(complete::char(','), complete::i32).parse(input)
+}
+
+fn read_input(data: impl AsRef<str>) -> Result<Vec<i32>, ParseError> {
+    let data = data.as_ref();
+
+    finish(Day7::DAY, data, parse(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let Alignment {
+            position,
+            fuel_spent: result,
+            ..
+        } = part_one(&input)?;
+
+        assert_eq!(position, 2);
+        assert_eq!(result, 37);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let Alignment {
+            position,
+            fuel_spent: result,
+            ..
+        } = part_two(&input)?;
+
+        assert_eq!(position, 5);
+        assert_eq!(result, 168);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,311 @@
+mod render;
+mod solver;
+mod table;
+
+pub use render::render;
+pub use solver::{solve, Mapping};
+pub use table::{DigitTable, FOURTEEN_SEGMENT, SEVEN_SEGMENT};
+
+use anyhow::{Context, Error, Result};
+use aoc_core::{
+    parse::{finish, tag, IResult},
+    ParseError, Solution,
+};
+use nom::{
+    character::complete::{alpha1, line_ending, space1},
+    combinator::map,
+    multi::separated_list1,
+    sequence::separated_pair,
+    Parser,
+};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Vec<(Groups, Groups)>;
+    type Output1 = usize;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        part_two(input)
+    }
+}
+
+fn part_one(input: &[(Groups, Groups)]) -> usize {
+    let table = DigitTable::default();
+
+    input
+        .iter()
+        .flat_map(|(_, o)| &o.groups)
+        .filter(|o| table.digits_lighting(o.len()) == 1)
+        .count()
+}
+
+fn part_two(input: &[(Groups, Groups)]) -> Result<u64> {
+    let table = DigitTable::default();
+
+    input
+        .iter()
+        .enumerate()
+        .map(|(entry, (signals, outputs))| {
+            calculate_output_value(&table, signals, outputs)
+                .with_context(|| format!("Failed to decode entry {}", entry + 1))
+        })
+        .sum()
+}
+
+/// The digits the outputs of an entry show, read as a number.
+pub fn calculate_output_value(
+    table: &DigitTable,
+    signals: &Groups,
+    outputs: &Groups,
+) -> Result<u64> {
+    let digits = decode(table, signals, outputs)?;
+
+    digits
+        .parse::<u64>()
+        .with_context(|| format!("The outputs show {digits:?}, which is not a number"))
+}
+
+/// The symbols of the digits the outputs of an entry show.
+pub fn decode(table: &DigitTable, signals: &Groups, outputs: &Groups) -> Result<String> {
+    let mapping = determine_code_mapping(table, signals, outputs)?;
+
+    outputs
+        .groups
+        .iter()
+        .map(|output| {
+            let pattern = output.as_bits(table)?;
+            match mapping.digit(pattern.0) {
+                Some(digit) => Ok(table.digits()[digit].0),
+                None => Err(Error::msg(format!(
+                    "Output {} could show any of {}",
+                    output.group,
+                    symbols(table, mapping.digits(pattern.0))
+                ))),
+            }
+        })
+        .collect()
+}
+
+/// Solves which digits the patterns of an entry show, failing if no wiring of the display fits
+/// them.
+pub fn determine_code_mapping(
+    table: &DigitTable,
+    signals: &Groups,
+    outputs: &Groups,
+) -> Result<Mapping> {
+    let patterns = signals
+        .groups
+        .iter()
+        .chain(&outputs.groups)
+        .map(|group| group.as_bits(table).map(|bits| bits.0))
+        .collect::<Result<Vec<u32>>>()?;
+
+    solve(table, &patterns).ok_or_else(|| {
+        Error::msg("The patterns contradict each other: no wiring shows them all as digits")
+    })
+}
+
+fn symbols(table: &DigitTable, digits: u64) -> String {
+    table
+        .digits()
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| digits & 1 << index != 0)
+        .map(|(_, (symbol, _))| symbol.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[derive(Clone)]
+pub struct Groups {
+    groups: Vec<Group>,
+}
+
+impl Groups {
+    pub fn new(groups: Vec<Group>) -> Self {
+        Groups { groups }
+    }
+
+    pub fn groups(&self) -> &[Group] {
+        &self.groups
+    }
+}
+
+/// The wires of a pattern as a bitmask, bit `i` for the `i`th segment letter of the display.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GroupAsBits(pub u32);
+
+impl GroupAsBits {
+    pub fn contains(&self, other: &Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+#[derive(Clone)]
+pub struct Group {
+    group: String,
+}
+
+impl Group {
+    pub fn new(group: &str) -> Self {
+        let group = group.to_string();
+        Group { group }
+    }
+
+    pub fn len(&self) -> usize {
+        self.group.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.group.is_empty()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.group
+    }
+
+    pub fn as_bits(&self, table: &DigitTable) -> Result<GroupAsBits> {
+        self.group.chars().try_fold(GroupAsBits(0), |bits, wire| {
+            let bit = table.bit(wire).with_context(|| {
+                format!("Wire {wire:?} of {} is not one of the display", self.group)
+            })?;
+            Ok(GroupAsBits(bits.0 | bit))
+        })
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<(Groups, Groups)>> {
+    separated_list1(line_ending, parse_line).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, (Groups, Groups)> {
+    separated_pair(parse_groups, tag(" | "), parse_groups).parse(input)
+}
+
+fn parse_groups(input: &str) -> IResult<&str, Groups> {
+    map(separated_list1(space1, parse_group), Groups::new).parse(input)
+}
+
+fn parse_group(input: &str) -> IResult<&str, Group> {
+    map(alpha1, Group::new).parse(input)
+}
+
+fn read_input(data: impl AsRef<str>) -> Result<Vec<(Groups, Groups)>, ParseError> {
+    let data = data.as_ref();
+
+    finish(Day8::DAY, data, parse(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_one(&input);
+
+        assert_eq!(count, 26);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_two(&input)?;
+
+        assert_eq!(count, 61229);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_errors() -> Result<()> {
+        let table = DigitTable::default();
+        let input = read_input("ab abc | acdeg ab\nab cd | ab\nab | ax")?;
+        let errors = input
+            .iter()
+            .map(|(signals, outputs)| decode(&table, signals, outputs).unwrap_err().to_string())
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            errors,
+            [
+                "Output acdeg could show any of 2, 5",
+                "The patterns contradict each other: no wiring shows them all as digits",
+                "Wire 'x' of ax is not one of the display",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render() -> Result<()> {
+        let table = DigitTable::default();
+        let input = read_input("ab abc | ab abc\nab cd | ab\nab abc | acdeg")?;
+
+        let (signals, outputs) = &input[0];
+        assert_eq!(
+            render(&table, signals, outputs)?,
+            concat!(
+                " _   _          _\n",
+                "|   | | ->   |   |\n",
+                "             |   |\n",
+            )
+        );
+
+        let (signals, outputs) = &input[1];
+        assert!(render(&table, signals, outputs)?.contains("no wiring"));
+        assert!(render(&DigitTable::fourteen_segment(), signals, outputs).is_err());
+
+        // 2 or 5: the wires that could drive either upper or lower right are highlighted
+        let (signals, outputs) = &input[2];
+        assert!(render(&table, signals, outputs)?.contains("\x1b[7m|\x1b[0m"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fourteen_segment() -> Result<()> {
+        let table = DigitTable::fourteen_segment();
+        // the fourteen-segment digits, with every wire moved one letter on
+        let signals = "bcdefglm cdl bcefhi bcdei cdghi bdeghi bdefghi bcd bcdefghi bcdeghi";
+        let input = read_input(format!("{signals} | cdl bcd bdeghi bcdefghi"))?;
+        let (signals, outputs) = &input[0];
+
+        let mapping = determine_code_mapping(&table, signals, outputs)?;
+
+        assert_eq!(calculate_output_value(&table, signals, outputs)?, 1758);
+        // the middle verticals and two of the diagonals are never lit, so they can't be told apart
+        assert_eq!(mapping.segments(13).count_ones(), 4);
+        assert_eq!(mapping.segment(1), Some(0));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{determine_code_mapping, DigitTable, Groups, Mapping};
+use anyhow::{Error, Result};
+
+/// Where every segment of a seven-segment digit is drawn in its three rows of three cells, in the
+/// order of the segments of the table.
+const CELLS: [(usize, usize, char); 7] = [
+    (0, 1, '_'),
+    (1, 0, '|'),
+    (1, 2, '|'),
+    (1, 1, '_'),
+    (2, 0, '|'),
+    (2, 2, '|'),
+    (2, 1, '_'),
+];
+
+/// A row of digits, each with its lit segments and the segments among them that are highlighted.
+struct Digits(Vec<(u32, u32)>);
+
+impl Digits {
+    fn rows(&self) -> [String; 3] {
+        let mut rows = [String::new(), String::new(), String::new()];
+        for (lit, highlighted) in &self.0 {
+            for (line, row) in rows.iter_mut().enumerate() {
+                for column in 0..3 {
+                    let segment = CELLS
+                        .iter()
+                        .position(|(r, c, _)| (*r, *c) == (line, column))
+                        .filter(|segment| lit & 1 << segment != 0);
+                    match segment {
+                        Some(segment) if highlighted & 1 << segment != 0 => {
+                            row.push_str(&format!("\x1b[7m{}\x1b[0m", CELLS[segment].2));
+                        }
+                        Some(segment) => row.push(CELLS[segment].2),
+                        None => row.push(' '),
+                    }
+                }
+                row.push(' ');
+            }
+        }
+
+        rows
+    }
+}
+
+/// Draws the outputs of an entry as seven-segment digits, first as the scrambled wires light them
+/// and then as the segments they drive. A digit the patterns don't settle lights every segment
+/// its wires could drive, with the undetermined ones in reverse video.
+///
+/// Fails for a display of other than seven segments or a wire that isn't one of the display, and
+/// draws only the scrambled digits when no wiring fits the patterns.
+pub fn render(table: &DigitTable, signals: &Groups, outputs: &Groups) -> Result<String> {
+    if table.segments().len() != CELLS.len() {
+        return Err(Error::msg("Only seven-segment displays can be drawn"));
+    }
+
+    let patterns = outputs
+        .groups()
+        .iter()
+        .map(|output| output.as_bits(table).map(|bits| bits.0))
+        .collect::<Result<Vec<u32>>>()?;
+    let before = Digits(patterns.iter().map(|pattern| (*pattern, 0)).collect()).rows();
+    let after = determine_code_mapping(table, signals, outputs).map(|mapping| {
+        let digits = patterns
+            .iter()
+            .map(|pattern| unscramble(table, &mapping, *pattern))
+            .collect();
+        Digits(digits).rows()
+    });
+
+    let mut art = String::new();
+    for (line, before) in before.iter().enumerate() {
+        let mut row = before.clone();
+        match &after {
+            Ok(after) => {
+                let arrow = if line == 1 { "->" } else { "  " };
+                row.push_str(&format!("{arrow} {}", after[line]));
+            }
+            Err(error) if line == 1 => row.push_str(&format!("   {error}")),
+            Err(_) => {}
+        }
+        art.push_str(row.trim_end());
+        art.push('\n');
+    }
+
+    Ok(art)
+}
+
+/// The segments a pattern lights once unscrambled, and those among them that are undetermined.
+fn unscramble(table: &DigitTable, mapping: &Mapping, pattern: u32) -> (u32, u32) {
+    if let Some(digit) = mapping.digit(pattern) {
+        return (table.digits()[digit].1, 0);
+    }
+
+    let (determined, undetermined) = (0..table.segments().len())
+        .filter(|wire| pattern & 1 << wire != 0)
+        .fold((0, 0), |(determined, undetermined), wire| {
+            match mapping.segment(wire) {
+                Some(segment) => (determined | 1 << segment, undetermined),
+                None => (determined, undetermined | mapping.segments(wire)),
+            }
+        });
+
+    (determined | undetermined, undetermined & !determined)
+}
@@ -0,0 +1,237 @@
+use crate::DigitTable;
+
+/// Everything the patterns of an entry tell about its wiring, over every wiring that fits them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mapping {
+    /// Every distinct pattern with the digits it can show, as a bitset of indices into the table.
+    patterns: Vec<(u32, u64)>,
+    /// For every wire, the segments it can drive.
+    wires: Vec<u32>,
+    /// How many ways there are to assign the patterns to digits.
+    solutions: usize,
+}
+
+impl Mapping {
+    /// The digits a pattern can show, as a bitset of indices into the table, which is empty for a
+    /// pattern that wasn't solved for.
+    pub fn digits(&self, pattern: u32) -> u64 {
+        self.patterns
+            .iter()
+            .find_map(|(p, digits)| (*p == pattern).then_some(*digits))
+            .unwrap_or_default()
+    }
+
+    /// The index of the only digit a pattern can show.
+    pub fn digit(&self, pattern: u32) -> Option<usize> {
+        let digits = self.digits(pattern);
+        (digits.count_ones() == 1).then(|| digits.trailing_zeros() as usize)
+    }
+
+    /// The segments a wire can drive, as a bitmask.
+    pub fn segments(&self, wire: usize) -> u32 {
+        self.wires[wire]
+    }
+
+    /// The only segment a wire can drive.
+    pub fn segment(&self, wire: usize) -> Option<usize> {
+        let segments = self.wires[wire];
+        (segments.count_ones() == 1).then(|| segments.trailing_zeros() as usize)
+    }
+
+    /// The number of ways the patterns fit the digits.
+    pub fn solutions(&self) -> usize {
+        self.solutions
+    }
+
+    /// Whether every pattern shows a single digit, even if some wires are left open because no
+    /// digit tells them apart.
+    pub fn is_unique(&self) -> bool {
+        self.patterns
+            .iter()
+            .all(|(_, digits)| digits.count_ones() == 1)
+    }
+
+    /// The patterns that can show more than one digit.
+    pub fn ambiguous(&self) -> impl Iterator<Item = (u32, u64)> + '_ {
+        self.patterns
+            .iter()
+            .copied()
+            .filter(|(_, digits)| digits.count_ones() > 1)
+    }
+}
+
+/// Finds every way to assign the patterns to distinct digits of the table through some
+/// permutation of the wires, or `None` if there is none.
+///
+/// Wires are tracked as bitmasks of the segments they may drive: showing a digit narrows the lit
+/// wires to its segments and the others to the rest, and a branch is dropped as soon as the wires
+/// can't be matched up with the segments one to one any more.
+pub fn solve(table: &DigitTable, patterns: &[u32]) -> Option<Mapping> {
+    let mut patterns = patterns.to_vec();
+    patterns.sort_unstable();
+    patterns.dedup();
+    let candidates = |pattern: &u32| {
+        table
+            .digits()
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, mask))| mask.count_ones() == pattern.count_ones())
+            .fold(0u64, |digits, (index, _)| digits | 1 << index)
+    };
+    // the most telling patterns first, so the wires narrow down early
+    patterns.sort_by_key(|pattern| candidates(pattern).count_ones());
+
+    let mut search = Search {
+        table,
+        candidates: patterns.iter().map(candidates).collect(),
+        mapping: Mapping {
+            patterns: patterns.iter().map(|p| (*p, 0)).collect(),
+            wires: vec![0; table.segments().len()],
+            solutions: 0,
+        },
+        patterns,
+        assigned: Vec::new(),
+    };
+    let wires = vec![table.all(); table.segments().len()];
+    search.assign(&wires, 0);
+
+    (search.mapping.solutions > 0).then_some(search.mapping)
+}
+
+struct Search<'a> {
+    table: &'a DigitTable,
+    patterns: Vec<u32>,
+    candidates: Vec<u64>,
+    assigned: Vec<usize>,
+    mapping: Mapping,
+}
+
+impl Search<'_> {
+    fn assign(&mut self, wires: &[u32], used: u64) {
+        let Some(pattern) = self.patterns.get(self.assigned.len()).copied() else {
+            self.record(wires);
+            return;
+        };
+
+        let mut digits = self.candidates[self.assigned.len()] & !used;
+        while digits != 0 {
+            let digit = digits.trailing_zeros() as usize;
+            digits &= digits - 1;
+
+            let mask = self.table.digits()[digit].1;
+            let narrowed = wires
+                .iter()
+                .enumerate()
+                .map(|(wire, segments)| {
+                    if pattern & 1 << wire != 0 {
+                        segments & mask
+                    } else {
+                        segments & !mask
+                    }
+                })
+                .collect::<Vec<u32>>();
+            if matches(&narrowed) {
+                self.assigned.push(digit);
+                self.assign(&narrowed, used | 1 << digit);
+                self.assigned.pop();
+            }
+        }
+    }
+
+    fn record(&mut self, wires: &[u32]) {
+        let mapping = &mut self.mapping;
+        mapping.solutions += 1;
+        for ((_, digits), digit) in mapping.patterns.iter_mut().zip(&self.assigned) {
+            *digits |= 1 << digit;
+        }
+        for (wire, segments) in wires.iter().enumerate() {
+            let mut left = *segments & !mapping.wires[wire];
+            while left != 0 {
+                let segment = 1 << left.trailing_zeros();
+                left &= left - 1;
+                // the wire can drive the segment if the others can still be matched without it
+                let fixed = wires
+                    .iter()
+                    .enumerate()
+                    .map(|(w, s)| if w == wire { segment } else { s & !segment })
+                    .collect::<Vec<u32>>();
+                if matches(&fixed) {
+                    mapping.wires[wire] |= segment;
+                }
+            }
+        }
+    }
+}
+
+/// Whether every wire can drive a different segment out of those it may drive.
+fn matches(wires: &[u32]) -> bool {
+    fn augment(wire: usize, wires: &[u32], owners: &mut [usize], seen: &mut u32) -> bool {
+        let mut options = wires[wire] & !*seen;
+        while options != 0 {
+            let segment = options.trailing_zeros() as usize;
+            options &= options - 1;
+            *seen |= 1 << segment;
+            if owners[segment] == usize::MAX || augment(owners[segment], wires, owners, seen) {
+                owners[segment] = wire;
+                return true;
+            }
+        }
+
+        false
+    }
+
+    let mut owners = vec![usize::MAX; 32];
+    (0..wires.len()).all(|wire| augment(wire, wires, &mut owners, &mut 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn masks(table: &DigitTable, patterns: &str) -> Vec<u32> {
+        patterns
+            .split_whitespace()
+            .map(|pattern| {
+                pattern
+                    .chars()
+                    .filter_map(|c| table.bit(c))
+                    .fold(0, |m, b| m | b)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unique() {
+        let table = DigitTable::seven_segment();
+        let patterns = masks(
+            &table,
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab",
+        );
+
+        let mapping = solve(&table, &patterns).unwrap();
+
+        assert!(mapping.is_unique());
+        assert_eq!(mapping.solutions(), 1);
+        assert_eq!(mapping.digit(masks(&table, "cdfeb")[0]), Some(5));
+        // wire d drives segment a
+        assert_eq!(mapping.segment(3), Some(0));
+    }
+
+    #[test]
+    fn test_ambiguous_and_contradictory() {
+        let table = DigitTable::seven_segment();
+
+        let mapping = solve(&table, &masks(&table, "ab abc")).unwrap();
+        assert!(mapping.is_unique());
+        assert_eq!(mapping.segments(0), 0b0100100);
+
+        let mapping = solve(&table, &masks(&table, "abcde")).unwrap();
+        assert!(!mapping.is_unique());
+        assert_eq!(
+            mapping.ambiguous().collect::<Vec<_>>(),
+            [(0b11111, 0b101100)]
+        );
+
+        assert_eq!(solve(&table, &masks(&table, "ab cd")), None);
+    }
+}
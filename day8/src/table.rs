@@ -0,0 +1,185 @@
+use anyhow::{Context, Error, Result};
+use std::str::FromStr;
+
+/// The seven-segment display of the puzzle.
+pub const SEVEN_SEGMENT: &str = "\
+segments: abcdefg
+0: abcefg
+1: cf
+2: acdeg
+3: acdfg
+4: bcdf
+5: abdfg
+6: abdefg
+7: acf
+8: abcdefg
+9: abcdfg
+";
+
+/// A fourteen-segment display: the seven segments, the middle one split into a left `g` and a
+/// right `h`, the diagonals `i`, `k`, `l` and `n` and the middle verticals `j` and `m`.
+pub const FOURTEEN_SEGMENT: &str = "\
+segments: abcdefghijklmn
+0: abcdefkl
+1: bck
+2: abdegh
+3: abcdh
+4: bcfgh
+5: acdfgh
+6: acdefgh
+7: abc
+8: abcdefgh
+9: abcdfgh
+";
+
+/// The segments of a display, each named by a letter, and the segments lit for every digit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DigitTable {
+    segments: Vec<char>,
+    digits: Vec<(char, u32)>,
+}
+
+impl DigitTable {
+    pub fn seven_segment() -> Self {
+        SEVEN_SEGMENT
+            .parse()
+            .expect("the seven-segment table is valid")
+    }
+
+    pub fn fourteen_segment() -> Self {
+        FOURTEEN_SEGMENT
+            .parse()
+            .expect("the fourteen-segment table is valid")
+    }
+
+    /// The letters of the segments, the one of bit 0 first.
+    pub fn segments(&self) -> &[char] {
+        &self.segments
+    }
+
+    /// Every digit with the segments it lights as a bitmask.
+    pub fn digits(&self) -> &[(char, u32)] {
+        &self.digits
+    }
+
+    /// The bit of a segment letter.
+    pub fn bit(&self, segment: char) -> Option<u32> {
+        let index = self.segments.iter().position(|s| *s == segment)?;
+        Some(1 << index)
+    }
+
+    /// The bitmask of every segment.
+    pub fn all(&self) -> u32 {
+        ((1u64 << self.segments.len()) - 1) as u32
+    }
+
+    /// How many digits light `count` segments.
+    pub fn digits_lighting(&self, count: usize) -> usize {
+        self.digits
+            .iter()
+            .filter(|(_, mask)| mask.count_ones() as usize == count)
+            .count()
+    }
+
+    fn mask(&self, segments: &str) -> Result<u32> {
+        segments.chars().try_fold(0, |mask, segment| {
+            let bit = self
+                .bit(segment)
+                .with_context(|| format!("Segment {segment:?} is not one of the display"))?;
+            Ok(mask | bit)
+        })
+    }
+}
+
+impl Default for DigitTable {
+    fn default() -> Self {
+        DigitTable::seven_segment()
+    }
+}
+
+impl FromStr for DigitTable {
+    type Err = Error;
+
+    /// Parses a table such as [`SEVEN_SEGMENT`]: a `segments:` line naming up to 32 segments, then
+    /// a line for every digit with its symbol and its lit segments. Blank lines and lines starting
+    /// with `#` are skipped.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+        let segments = lines
+            .next()
+            .and_then(|line| line.strip_prefix("segments:"))
+            .context("The table should start with a segments: line")?
+            .trim()
+            .chars()
+            .collect::<Vec<char>>();
+        if segments.is_empty() || segments.len() > 32 {
+            return Err(Error::msg("A display has between 1 and 32 segments"));
+        }
+        if let Some(letter) = segments
+            .iter()
+            .enumerate()
+            .find_map(|(i, s)| segments[..i].contains(s).then_some(s))
+        {
+            return Err(Error::msg(format!("Segment {letter:?} is named twice")));
+        }
+
+        let mut table = DigitTable {
+            segments,
+            digits: Vec::new(),
+        };
+        for line in lines {
+            let (symbol, lit) = line
+                .split_once(':')
+                .with_context(|| format!("Expected a digit such as 1: cf, got {line:?}"))?;
+            let mut symbol = symbol.trim().chars();
+            let (Some(symbol), None) = (symbol.next(), symbol.next()) else {
+                return Err(Error::msg(format!(
+                    "A digit is a single symbol in {line:?}"
+                )));
+            };
+            let mask = table.mask(lit.trim())?;
+            if let Some((other, _)) = table.digits.iter().find(|(_, m)| *m == mask) {
+                return Err(Error::msg(format!(
+                    "Digits {other} and {symbol} light the same segments"
+                )));
+            }
+            table.digits.push((symbol, mask));
+        }
+        if table.digits.is_empty() || table.digits.len() > 64 {
+            return Err(Error::msg("A display has between 1 and 64 digits"));
+        }
+
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables() -> Result<()> {
+        let table = DigitTable::seven_segment();
+        assert_eq!(table.digits()[1], ('1', 0b0100100));
+        assert_eq!(table.digits_lighting(5), 3);
+        assert_eq!(DigitTable::fourteen_segment().all(), (1 << 14) - 1);
+
+        let table = "# binary\nsegments: xy\n0: x\n1: y\n".parse::<DigitTable>()?;
+        assert_eq!(table.digits(), [('0', 1), ('1', 2)]);
+
+        for invalid in [
+            "0: ab",
+            "segments: aa\n0: a",
+            "segments: ab\n0: c",
+            "segments: ab\n0: a\n1: a",
+        ] {
+            assert!(invalid.parse::<DigitTable>().is_err(), "{invalid}");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,426 @@
+use anyhow::Error;
+use grid::{Grid, Point, ADJACENT, ORTHOGONAL};
+use std::{cmp::Reverse, str::FromStr};
+
+/// The height of the walls between basins in the puzzle, which belong to none of them.
+pub const WALL: u8 = 9;
+
+/// Offsets of the six neighbours of a cell on a hexagonal grid in axial coordinates, where every
+/// row is shifted half a cell to the right of the one above.
+pub const HEXAGONAL: [(isize, isize); 6] = [(0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1)];
+
+/// Which cells are neighbours.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Adjacency {
+    /// Cells sharing an edge, as in the puzzle.
+    #[default]
+    Orthogonal,
+    /// Cells sharing an edge or a corner.
+    Adjacent,
+    /// The cells of a hexagonal grid, see [`HEXAGONAL`].
+    Hexagonal,
+}
+
+impl Adjacency {
+    pub fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Adjacency::Orthogonal => &ORTHOGONAL,
+            Adjacency::Adjacent => &ADJACENT,
+            Adjacency::Hexagonal => &HEXAGONAL,
+        }
+    }
+}
+
+impl FromStr for Adjacency {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "orthogonal" => Ok(Adjacency::Orthogonal),
+            "adjacent" => Ok(Adjacency::Adjacent),
+            "hexagonal" => Ok(Adjacency::Hexagonal),
+            adjacency => Err(Error::msg(format!(
+                "Unknown adjacency {adjacency:?}, expected orthogonal, adjacent or hexagonal"
+            ))),
+        }
+    }
+}
+
+/// Which cells are the low points of a basin.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LowPoints {
+    /// Cells lower than all of their neighbours, as in the puzzle.
+    #[default]
+    Strict,
+    /// Cells no neighbour is lower than, so every cell of a level floor counts.
+    Level,
+}
+
+impl FromStr for LowPoints {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "strict" => Ok(LowPoints::Strict),
+            "level" => Ok(LowPoints::Level),
+            low_points => Err(Error::msg(format!(
+                "Unknown low points {low_points:?}, expected strict or level"
+            ))),
+        }
+    }
+}
+
+/// How a height map splits into basins.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rules {
+    /// Cells at least this high are walls.
+    pub wall: u8,
+    pub adjacency: Adjacency,
+    /// Whether the edges of the map wrap around as on a torus.
+    pub wrapping: bool,
+    pub low_points: LowPoints,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            wall: WALL,
+            adjacency: Adjacency::default(),
+            wrapping: false,
+            low_points: LowPoints::default(),
+        }
+    }
+}
+
+impl Rules {
+    /// The neighbours of a point, one for every offset that stays on the map.
+    fn neighbours(&self, heights: &Grid<u8>, point: Point) -> Vec<Point> {
+        let offsets = self.adjacency.offsets();
+        if self.wrapping {
+            heights.neighbours_wrapping(point, offsets).collect()
+        } else {
+            heights.neighbours(point, offsets).collect()
+        }
+    }
+}
+
+/// A basin of the height map: cells that connect without crossing a wall.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Basin {
+    /// The number of cells.
+    pub size: usize,
+    /// The low points, in reading order.
+    pub low_points: Vec<Point>,
+    /// The height of the lowest cell.
+    pub floor: u8,
+    /// How far the highest cell stands above the lowest.
+    pub depth: u8,
+    /// The top left and bottom right corners of the smallest rectangle around the basin, taking
+    /// the cells where they lie on the map even when the edges wrap.
+    pub bounds: (Point, Point),
+    /// The number of times a cell of the basin neighbours a wall or, unless the edges wrap, the
+    /// edge of the map.
+    pub perimeter: usize,
+}
+
+impl Basin {
+    /// The low point of the basin, if it has exactly one. A basin may have several, or with
+    /// [`LowPoints::Strict`] none when its lowest cells are level with each other.
+    pub fn low_point(&self) -> Option<Point> {
+        match self.low_points.as_slice() {
+            [point] => Some(*point),
+            _ => None,
+        }
+    }
+}
+
+/// Every cell of a height map labelled with its basin.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BasinMap {
+    labels: Grid<Option<usize>>,
+    basins: Vec<Basin>,
+}
+
+impl BasinMap {
+    /// Labels the basins of a height map with the rules of the puzzle.
+    pub fn new(heights: &Grid<u8>) -> Self {
+        BasinMap::with_rules(heights, Rules::default())
+    }
+
+    /// Labels the basins of the height map in a single pass, joining every cell with its
+    /// neighbours in a union-find forest, then gathers their statistics in a second one. Basins
+    /// are numbered in the reading order of their first cell.
+    pub fn with_rules(heights: &Grid<u8>, rules: Rules) -> Self {
+        let index = |(x, y): Point| y * heights.width() + x;
+        let open = |point: Point| heights[point] < rules.wall;
+
+        let mut sets = DisjointSets::new(heights.len());
+        for point in heights.points().filter(|point| open(*point)) {
+            for neighbour in rules.neighbours(heights, point) {
+                if open(neighbour) {
+                    sets.union(index(point), index(neighbour));
+                }
+            }
+        }
+
+        let mut roots = vec![None; heights.len()];
+        let mut basins = Vec::<Basin>::new();
+        let labels = Grid::from_fn(heights.width(), heights.height(), |point| {
+            if !open(point) {
+                return None;
+            }
+            let root = sets.find(index(point));
+            let label = *roots[root].get_or_insert(basins.len());
+            if label == basins.len() {
+                basins.push(Basin {
+                    size: 0,
+                    low_points: Vec::new(),
+                    floor: u8::MAX,
+                    depth: 0,
+                    bounds: (point, point),
+                    perimeter: 0,
+                });
+            }
+            Some(label)
+        });
+
+        let mut peaks = vec![0; basins.len()];
+        for (point, label) in labels.iter() {
+            let Some(label) = label else {
+                continue;
+            };
+            let height = heights[point];
+            let basin = &mut basins[*label];
+            let neighbours = rules.neighbours(heights, point);
+
+            basin.size += 1;
+            // on a narrow torus a cell can be its own neighbour
+            let mut others = neighbours.iter().filter(|n| **n != point);
+            let low = match rules.low_points {
+                LowPoints::Strict => others.all(|n| heights[*n] > height),
+                LowPoints::Level => others.all(|n| heights[*n] >= height),
+            };
+            if low {
+                basin.low_points.push(point);
+            }
+            basin.floor = basin.floor.min(height);
+            peaks[*label] = peaks[*label].max(height);
+            let ((left, top), (right, bottom)) = basin.bounds;
+            basin.bounds = (
+                (left.min(point.0), top.min(point.1)),
+                (right.max(point.0), bottom.max(point.1)),
+            );
+            let walls = neighbours.iter().filter(|n| !open(**n)).count();
+            let edges = match rules.wrapping {
+                true => 0,
+                false => rules.adjacency.offsets().len() - neighbours.len(),
+            };
+            basin.perimeter += walls + edges;
+        }
+        for (basin, peak) in basins.iter_mut().zip(peaks) {
+            basin.depth = peak - basin.floor;
+        }
+
+        BasinMap { labels, basins }
+    }
+
+    /// The basins, numbered by their labels.
+    pub fn basins(&self) -> &[Basin] {
+        &self.basins
+    }
+
+    /// The label of the basin of every cell, `None` for walls.
+    pub fn labels(&self) -> &Grid<Option<usize>> {
+        &self.labels
+    }
+
+    /// The basin a cell belongs to.
+    pub fn basin_at(&self, point: Point) -> Option<&Basin> {
+        let label = (*self.labels.get(point)?)?;
+        Some(&self.basins[label])
+    }
+
+    /// The low points of every basin.
+    pub fn low_points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.basins
+            .iter()
+            .flat_map(|basin| basin.low_points.iter().copied())
+    }
+
+    /// The total risk level of the low points of every basin, with `risk` giving the risk level
+    /// of a low point from its height.
+    pub fn risk_level(&self, heights: &Grid<u8>, risk: impl Fn(u8) -> usize) -> usize {
+        self.low_points().map(|point| risk(heights[point])).sum()
+    }
+
+    /// The `count` largest basins, the largest first and basins of the same size in label order.
+    pub fn largest(&self, count: usize) -> Vec<&Basin> {
+        let mut basins = self.basins.iter().collect::<Vec<&Basin>>();
+        basins.sort_by_key(|basin| Reverse(basin.size));
+        basins.truncate(count);
+
+        basins
+    }
+}
+
+/// A union-find forest over `0..len`, with path halving and union by size.
+struct DisjointSets {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(len: usize) -> Self {
+        DisjointSets {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, mut element: usize) -> usize {
+        while self.parents[element] != element {
+            self.parents[element] = self.parents[self.parents[element]];
+            element = self.parents[element];
+        }
+
+        element
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            (a, b) = (b, a);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    fn heights(rows: &[&str]) -> Result<Grid<u8>> {
+        let rows = rows
+            .iter()
+            .map(|row| row.bytes().map(|b| b - b'0').collect())
+            .collect();
+
+        Ok(Grid::from_rows(rows)?)
+    }
+
+    #[test]
+    fn test_statistics() -> Result<()> {
+        let map = BasinMap::new(&heights(&["21999", "39878", "98567"])?);
+
+        assert_eq!(
+            map.basins(),
+            [
+                Basin {
+                    size: 3,
+                    low_points: vec![(1, 0)],
+                    floor: 1,
+                    depth: 2,
+                    bounds: ((0, 0), (1, 1)),
+                    perimeter: 8,
+                },
+                Basin {
+                    size: 7,
+                    low_points: vec![(2, 2)],
+                    floor: 5,
+                    depth: 3,
+                    bounds: ((1, 1), (4, 2)),
+                    perimeter: 12,
+                },
+            ]
+        );
+        assert_eq!(map.basin_at((3, 1)).map(|b| b.size), Some(7));
+        assert_eq!(map.basin_at((2, 0)), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rules() -> Result<()> {
+        let heights = heights(&["19991", "91919", "99999", "19995"])?;
+        let sizes = |rules: Rules| {
+            let map = BasinMap::with_rules(&heights, rules);
+            map.basins().iter().map(|b| b.size).collect::<Vec<usize>>()
+        };
+
+        assert_eq!(sizes(Rules::default()), [1, 1, 1, 1, 1, 1]);
+        // the cells of the second row touch the top corners diagonally
+        let adjacent = Rules {
+            adjacency: Adjacency::Adjacent,
+            ..Rules::default()
+        };
+        assert_eq!(sizes(adjacent), [2, 2, 1, 1]);
+        // (3, 1) neighbours (4, 0), but (1, 1) doesn't neighbour (0, 0)
+        let hexagonal = Rules {
+            adjacency: Adjacency::Hexagonal,
+            ..Rules::default()
+        };
+        assert_eq!(sizes(hexagonal), [1, 2, 1, 1, 1]);
+        // the corners meet around the edges
+        let wrapping = Rules {
+            wrapping: true,
+            ..Rules::default()
+        };
+        assert_eq!(sizes(wrapping), [4, 1, 1]);
+        // the five is a wall too
+        let wall = Rules {
+            wall: 5,
+            wrapping: true,
+            ..Rules::default()
+        };
+        assert_eq!(sizes(wall), [3, 1, 1]);
+        // on a map two cells wide the wall is both the left and the right neighbour
+        let narrow = self::heights(&["19"])?;
+        let map = BasinMap::with_rules(&narrow, wrapping);
+        assert_eq!(map.basins()[0].perimeter, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_risk_level() -> Result<()> {
+        let heights = heights(&["11929", "22919"])?;
+
+        let strict = BasinMap::new(&heights);
+        let level = BasinMap::with_rules(
+            &heights,
+            Rules {
+                low_points: LowPoints::Level,
+                ..Rules::default()
+            },
+        );
+
+        assert_eq!(strict.risk_level(&heights, |height| 1 + height as usize), 2);
+        assert_eq!(level.risk_level(&heights, |height| 1 + height as usize), 6);
+        assert_eq!(level.risk_level(&heights, usize::from), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_without_unique_low_point() -> Result<()> {
+        let map = BasinMap::new(&heights(&["11929", "22919", "99999", "31213"])?);
+
+        let low_points = map
+            .basins()
+            .iter()
+            .map(|basin| basin.low_points.len())
+            .collect::<Vec<usize>>();
+
+        // a level floor, a lone cell and a basin with two low points
+        assert_eq!(low_points, [0, 1, 2]);
+        assert_eq!(map.basins()[2].low_point(), None);
+        assert_eq!(map.basins()[1].low_point(), Some((3, 1)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+mod basins;
+
+pub use basins::{Adjacency, Basin, BasinMap, LowPoints, Rules, HEXAGONAL, WALL};
+
+use anyhow::Result;
+use aoc_core::{parse::finish, ParseError, Solution};
+use grid::{parse::digits, Grid};
+
+#[cfg(feature = "embedded-input")]
+const DATA: &str = include_str!("input.txt");
+#[cfg(feature = "embedded-input")]
+const EMBEDDED: Option<&str> = Some(DATA);
+#[cfg(not(feature = "embedded-input"))]
+const EMBEDDED: Option<&str> = None;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const EMBEDDED: Option<&'static str> = EMBEDDED;
+
+    type Input = Grid<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Output1> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Output2> {
+        Ok(part_two(input))
+    }
+}
+
+fn part_one(grid: &Grid<u8>) -> usize {
+    BasinMap::new(grid).risk_level(grid, |height| 1 + height as usize)
+}
+
+fn part_two(grid: &Grid<u8>) -> usize {
+    BasinMap::new(grid)
+        .largest(3)
+        .iter()
+        .map(|basin| basin.size)
+        .product()
+}
+
+fn read_input(data: impl AsRef<str>) -> Result<Grid<u8>, ParseError> {
+    let data = data.as_ref();
+
+    finish(Day9::DAY, data, digits(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_one(&input);
+
+        assert_eq!(count, 15);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let input = read_input(TESTDATA)?;
+
+        let count = part_two(&input);
+
+        assert_eq!(count, 1134);
+
+        Ok(())
+    }
+}
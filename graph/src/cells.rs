@@ -0,0 +1,72 @@
+use crate::Graph;
+use grid::{Grid, Point};
+
+/// The cells of a grid as a graph, linking every cell to its neighbours at `offsets`.
+///
+/// `edge` decides whether a step from one cell value to another is allowed and what it costs.
+pub struct Cells<'a, T, E> {
+    grid: &'a Grid<T>,
+    offsets: &'static [(isize, isize)],
+    edge: E,
+}
+
+impl<'a, T, E> Cells<'a, T, E>
+where
+    E: Fn(&T, &T) -> Option<usize>,
+{
+    pub fn new(grid: &'a Grid<T>, offsets: &'static [(isize, isize)], edge: E) -> Self {
+        Cells {
+            grid,
+            offsets,
+            edge,
+        }
+    }
+}
+
+impl<T, E> Graph for Cells<'_, T, E>
+where
+    E: Fn(&T, &T) -> Option<usize>,
+{
+    type Node = Point;
+
+    fn neighbours(&self, point: &Point) -> impl Iterator<Item = Point> {
+        let from = &self.grid[*point];
+        self.grid
+            .neighbours(*point, self.offsets)
+            .filter(move |to| (self.edge)(from, &self.grid[*to]).is_some())
+    }
+
+    fn cost(&self, from: &Point, to: &Point) -> usize {
+        (self.edge)(&self.grid[*from], &self.grid[*to]).unwrap_or(usize::MAX)
+    }
+}
+
+/// The A* heuristic of a grid where every step costs at least one: the Manhattan distance to `goal`.
+pub fn manhattan(goal: Point) -> impl Fn(&Point) -> usize {
+    move |(x, y)| x.abs_diff(goal.0) + y.abs_diff(goal.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{astar, components, dijkstra};
+    use grid::ORTHOGONAL;
+
+    #[test]
+    fn test_cells() {
+        let grid = Grid::from_rows(vec![vec![1, 9, 1], vec![1, 9, 1], vec![1, 1, 1]]).unwrap();
+        let risk = Cells::new(&grid, &ORTHOGONAL, |_, to| Some(*to as usize));
+        let goal = (2, 0);
+
+        let (path, cost) = dijkstra(&risk, (0, 0), |p| *p == goal).unwrap();
+        assert_eq!((path.len(), cost), (7, 6));
+        assert_eq!(
+            astar(&risk, (0, 0), |p| *p == goal, manhattan(goal)).map(|(_, cost)| cost),
+            Some(6)
+        );
+
+        let open = Cells::new(&grid, &ORTHOGONAL, |_, to| (*to != 9).then_some(1));
+        let points = grid.points().filter(|p| grid[*p] != 9);
+        assert_eq!(components(&open, points).len(), 1);
+    }
+}
@@ -0,0 +1,23 @@
+//! Searches over graphs given by the neighbours of their nodes, shared by the days that walk a
+//! grid or a map of caves.
+
+mod cells;
+mod search;
+
+pub use cells::{manhattan, Cells};
+pub use search::{astar, bfs, components, count_paths, dfs, dijkstra, reachable};
+
+use std::hash::Hash;
+
+/// A directed graph, known only through the neighbours of each node.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// The nodes an edge leads to from `node`.
+    fn neighbours(&self, node: &Self::Node) -> impl Iterator<Item = Self::Node>;
+
+    /// The cost of the edge from `from` to its neighbour `to`, one unless overridden.
+    fn cost(&self, _from: &Self::Node, _to: &Self::Node) -> usize {
+        1
+    }
+}
@@ -0,0 +1,265 @@
+use crate::Graph;
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+};
+
+/// Every node reachable from `start`, in breadth-first order.
+pub fn reachable<G: Graph>(graph: &G, start: G::Node) -> Vec<G::Node> {
+    let mut seen = HashSet::from([start.clone()]);
+    let mut order = vec![start];
+
+    let mut next = 0;
+    while let Some(node) = order.get(next).cloned() {
+        for neighbour in graph.neighbours(&node) {
+            if seen.insert(neighbour.clone()) {
+                order.push(neighbour);
+            }
+        }
+        next += 1;
+    }
+
+    order
+}
+
+/// A path from `start` to the nearest goal with the fewest edges, ignoring their costs.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Vec<G::Node>> {
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(unwind(&parents, node));
+        }
+        for neighbour in graph.neighbours(&node) {
+            if let Entry::Vacant(entry) = parents.entry(neighbour.clone()) {
+                entry.insert(Some(node.clone()));
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    None
+}
+
+/// Every node reachable from `start`, in depth-first preorder.
+pub fn dfs<G: Graph>(graph: &G, start: G::Node) -> Vec<G::Node> {
+    let mut seen = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        // pushed in reverse so the first neighbour is visited first
+        let neighbours = graph.neighbours(&node).collect::<Vec<_>>();
+        stack.extend(neighbours.into_iter().rev().filter(|n| !seen.contains(n)));
+        order.push(node);
+    }
+
+    order
+}
+
+/// The cheapest path from `start` to a goal and its cost.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<(Vec<G::Node>, usize)> {
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// The cheapest path from `start` to a goal and its cost, exploring the nodes that `heuristic`
+/// estimates closest to a goal first.
+///
+/// The heuristic must never overestimate the remaining cost, or a more expensive path may be found.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    mut heuristic: impl FnMut(&G::Node) -> usize,
+) -> Option<(Vec<G::Node>, usize)> {
+    // nodes are kept in a list and referred to by index, so they don't need to be ordered
+    let mut nodes = vec![start.clone()];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut parents = vec![usize::MAX];
+    let mut costs = vec![0];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+        let node = nodes[index].clone();
+        if is_goal(&node) {
+            let mut path = vec![node];
+            let mut index = parents[index];
+            while index != usize::MAX {
+                path.push(nodes[index].clone());
+                index = parents[index];
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+
+        for neighbour in graph.neighbours(&node) {
+            let next_cost = cost + graph.cost(&node, &neighbour);
+            let next = match indices.entry(neighbour) {
+                Entry::Vacant(entry) => {
+                    nodes.push(entry.key().clone());
+                    parents.push(index);
+                    costs.push(next_cost);
+                    *entry.insert(nodes.len() - 1)
+                }
+                Entry::Occupied(entry) if next_cost < costs[*entry.get()] => {
+                    let next = *entry.get();
+                    parents[next] = index;
+                    costs[next] = next_cost;
+                    next
+                }
+                Entry::Occupied(_) => continue,
+            };
+            queue.push(Reverse((
+                next_cost + heuristic(&nodes[next]),
+                next_cost,
+                next,
+            )));
+        }
+    }
+
+    None
+}
+
+/// The connected parts of the graph among `nodes`, each listed in breadth-first order from the
+/// first of its nodes.
+///
+/// The graph should be undirected, with an edge back for every edge, or parts may be split.
+pub fn components<G: Graph>(
+    graph: &G,
+    nodes: impl IntoIterator<Item = G::Node>,
+) -> Vec<Vec<G::Node>> {
+    let mut labelled = HashSet::new();
+    let mut components = Vec::new();
+
+    for node in nodes {
+        if labelled.contains(&node) {
+            continue;
+        }
+        let component = reachable(graph, node);
+        labelled.extend(component.iter().cloned());
+        components.push(component);
+    }
+
+    components
+}
+
+/// The number of distinct paths from `start` to a goal, without going on past a goal.
+///
+/// Counts are remembered per node, so the graph must be acyclic: encode whatever limits a walk,
+/// such as the nodes visited so far, into the nodes themselves. Nothing checks this, and a cycle
+/// reachable from `start` recurses until the stack overflows, so callers must rule cycles out.
+pub fn count_paths<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl Fn(&G::Node) -> bool,
+) -> usize {
+    fn count<G: Graph>(
+        graph: &G,
+        node: G::Node,
+        is_goal: &impl Fn(&G::Node) -> bool,
+        counts: &mut HashMap<G::Node, usize>,
+    ) -> usize {
+        if is_goal(&node) {
+            return 1;
+        }
+        if let Some(count) = counts.get(&node) {
+            return *count;
+        }
+
+        let paths = graph
+            .neighbours(&node)
+            .map(|neighbour| count(graph, neighbour, is_goal, counts))
+            .sum();
+        counts.insert(node, paths);
+
+        paths
+    }
+
+    count(graph, start, &is_goal, &mut HashMap::new())
+}
+
+fn unwind<N: Clone + Eq + std::hash::Hash>(parents: &HashMap<N, Option<N>>, goal: N) -> Vec<N> {
+    let mut path = vec![goal];
+    while let Some(Some(parent)) = path.last().and_then(|node| parents.get(node)) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Edges of a small graph: a diamond 0 -> {1, 2} -> 3 with 1 -> 3 expensive, and 4 <-> 5 apart.
+    struct Diamond;
+
+    impl Graph for Diamond {
+        type Node = u8;
+
+        fn neighbours(&self, node: &u8) -> impl Iterator<Item = u8> {
+            let neighbours: &[u8] = match node {
+                0 => &[1, 2],
+                1 | 2 => &[3],
+                4 => &[5],
+                5 => &[4],
+                _ => &[],
+            };
+            neighbours.iter().copied()
+        }
+
+        fn cost(&self, from: &u8, to: &u8) -> usize {
+            if (*from, *to) == (1, 3) {
+                10
+            } else {
+                1
+            }
+        }
+    }
+
+    #[test]
+    fn test_traversals() {
+        assert_eq!(reachable(&Diamond, 0), vec![0, 1, 2, 3]);
+        assert_eq!(dfs(&Diamond, 0), vec![0, 1, 3, 2]);
+        assert_eq!(bfs(&Diamond, 0, |n| *n == 3), Some(vec![0, 1, 3]));
+        assert_eq!(bfs(&Diamond, 0, |n| *n == 4), None);
+    }
+
+    #[test]
+    fn test_cheapest_path() {
+        assert_eq!(dijkstra(&Diamond, 0, |n| *n == 3), Some((vec![0, 2, 3], 2)));
+        assert_eq!(
+            astar(&Diamond, 0, |n| *n == 3, |n| usize::from(*n < 3)),
+            Some((vec![0, 2, 3], 2))
+        );
+    }
+
+    #[test]
+    fn test_components() {
+        let components = components(&Diamond, [4, 3, 5]);
+
+        assert_eq!(components, vec![vec![4, 5], vec![3]]);
+    }
+
+    #[test]
+    fn test_count_paths() {
+        assert_eq!(count_paths(&Diamond, 0, |n| *n == 3), 2);
+        assert_eq!(count_paths(&Diamond, 0, |n| *n == 4), 0);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{Grid, Position};
+
+/// A grid stretching out forever: a dense window, with every cell beyond it holding the same
+/// background value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InfiniteGrid<T> {
+    window: Grid<T>,
+    origin: Position,
+    background: T,
+}
+
+impl<T> InfiniteGrid<T> {
+    /// Places `window` with its top left cell at `(0, 0)`.
+    pub fn new(window: Grid<T>, background: T) -> Self {
+        InfiniteGrid {
+            window,
+            origin: (0, 0),
+            background,
+        }
+    }
+
+    pub fn get(&self, (x, y): Position) -> &T {
+        self.window
+            .get_signed((x - self.origin.0, y - self.origin.1))
+            .unwrap_or(&self.background)
+    }
+
+    /// The part of the grid that isn't all background.
+    pub fn window(&self) -> &Grid<T> {
+        &self.window
+    }
+
+    /// Position of the top left cell of the window.
+    pub fn origin(&self) -> Position {
+        self.origin
+    }
+
+    pub fn background(&self) -> &T {
+        &self.background
+    }
+
+    /// Every position of the window in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let (x0, y0) = self.origin;
+        self.window
+            .points()
+            .map(move |(x, y)| (x0 + x as isize, y0 + y as isize))
+    }
+
+    /// Computes the next generation of the grid, with a window grown by `grow` cells on every side.
+    ///
+    /// `cell` gives the value of each position of the new window; the caller works out what the
+    /// background turns into.
+    pub fn step(&self, grow: usize, background: T, mut cell: impl FnMut(Position) -> T) -> Self {
+        let origin = (self.origin.0 - grow as isize, self.origin.1 - grow as isize);
+        let window = Grid::from_fn(
+            self.window.width() + 2 * grow,
+            self.window.height() + 2 * grow,
+            |(x, y)| cell((origin.0 + x as isize, origin.1 + y as isize)),
+        );
+
+        InfiniteGrid {
+            window,
+            origin,
+            background,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let grid = InfiniteGrid::new(Grid::filled(1, 1, true), false);
+
+        // every cell turns on when it or a neighbour is on, and the background flips
+        let grid = grid.step(1, true, |(x, y)| {
+            crate::ADJACENT
+                .iter()
+                .chain([&(0, 0)])
+                .any(|(dx, dy)| *grid.get((x + dx, y + dy)))
+        });
+
+        assert_eq!(grid.origin(), (-1, -1));
+        assert_eq!(grid.window().values().filter(|v| **v).count(), 9);
+        assert!(*grid.get((5, -7)));
+        assert_eq!(grid.positions().next(), Some((-1, -1)));
+    }
+}
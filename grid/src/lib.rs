@@ -0,0 +1,368 @@
+//! Two-dimensional grids shared by the days: a dense [`Grid`] stored row by row, a [`SparseGrid`]
+//! holding only the positions that are set and an [`InfiniteGrid`] with the same background
+//! everywhere beyond its edges.
+
+mod infinite;
+pub mod parse;
+mod sparse;
+
+pub use infinite::InfiniteGrid;
+pub use sparse::{Position, SparseGrid};
+
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+/// A cell of a [`Grid`] as `(x, y)`, with `y` growing downwards.
+pub type Point = (usize, usize);
+
+/// Offsets of the neighbours sharing an edge with a cell, in reading order.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the neighbours sharing an edge or a corner with a cell, in reading order.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid with its cells stored row by row.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+/// A row of a grid that isn't as long as the first one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RaggedRow {
+    pub row: usize,
+    pub len: usize,
+    pub width: usize,
+}
+
+impl Display for RaggedRow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "row {} has {} cells instead of {}",
+            self.row, self.len, self.width
+        )
+    }
+}
+
+impl std::error::Error for RaggedRow {}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all be as long as the first one.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, RaggedRow> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (row, values) in rows.into_iter().enumerate() {
+            if values.len() != width {
+                return Err(RaggedRow {
+                    row,
+                    len: values.len(),
+                    width,
+                });
+            }
+            cells.extend(values);
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Builds a grid of `width` by `height` cells, asking `cell` for the value of every point.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point).then(|| &self.cells[self.index(point)])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            let index = self.index(point);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at signed coordinates, `None` when they fall outside of the grid.
+    pub fn get_signed(&self, (x, y): Position) -> Option<&T> {
+        let point = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        self.get(point)
+    }
+
+    /// The cell at signed coordinates, wrapping around the edges as on a torus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty.
+    pub fn get_wrapping(&self, position: Position) -> &T {
+        &self[self.wrap(position)]
+    }
+
+    /// Every point of the grid in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell of the grid with its point, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The neighbours of a point within the grid, at the given offsets.
+    ///
+    /// The iterator doesn't borrow the grid, so cells can be changed while walking it.
+    pub fn neighbours(
+        &self,
+        (x, y): Point,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + use<T> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let x = x.checked_add_signed(*dx).filter(|x| *x < width)?;
+            let y = y.checked_add_signed(*dy).filter(|y| *y < height)?;
+            Some((x, y))
+        })
+    }
+
+    /// The neighbours of a point at the given offsets, wrapping around the edges as on a torus.
+    ///
+    /// On a grid narrower or lower than the offsets reach, several offsets can land on the same
+    /// cell, including the point itself; each cell is only given once, in the order of the first
+    /// offset reaching it. An empty grid has no neighbours.
+    pub fn neighbours_wrapping(
+        &self,
+        (x, y): Point,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + use<T> {
+        let mut neighbours = Vec::with_capacity(offsets.len());
+        if !self.is_empty() {
+            for (dx, dy) in offsets {
+                let neighbour = self.wrap((x as isize + dx, y as isize + dy));
+                if !neighbours.contains(&neighbour) {
+                    neighbours.push(neighbour);
+                }
+            }
+        }
+        neighbours.into_iter()
+    }
+
+    /// The up to four neighbours sharing an edge with a point.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + use<T> {
+        self.neighbours(point, &ORTHOGONAL)
+    }
+
+    /// The up to eight neighbours sharing an edge or a corner with a point.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + use<T> {
+        self.neighbours(point, &ADJACENT)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Draws the grid as lines of text, one character per cell.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            output.extend(row.iter().map(&mut cell));
+            output.push('\n');
+        }
+
+        output
+    }
+
+    fn index(&self, (x, y): Point) -> usize {
+        y * self.width + x
+    }
+
+    fn wrap(&self, (x, y): Position) -> Point {
+        (
+            x.rem_euclid(self.width as isize) as usize,
+            y.rem_euclid(self.height as isize) as usize,
+        )
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid of `width` by `height` cells that all hold `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        assert!(self.contains(point), "{point:?} is outside of the grid");
+        &self.cells[Grid::index(self, point)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        assert!(self.contains(point), "{point:?} is outside of the grid");
+        let index = Grid::index(self, point);
+        &mut self.cells[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        Grid::from_fn(3, 2, |(x, y)| (y * 3 + x) as u8)
+    }
+
+    #[test]
+    fn test_access() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height(), grid.len()), (3, 2, 6));
+        assert_eq!(grid[(2, 1)], 5);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get_signed((-1, 0)), None);
+        assert_eq!(grid.get_signed((1, 1)), Some(&4));
+        assert_eq!(grid.get_wrapping((-1, -1)), &5);
+        assert_eq!(grid.get_wrapping((4, 2)), &1);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let mut grid = grid();
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(
+            grid.neighbours_wrapping((0, 0), &ORTHOGONAL)
+                .collect::<Vec<_>>(),
+            vec![(0, 1), (2, 0), (1, 0)]
+        );
+
+        for point in grid.neighbours8((1, 1)) {
+            grid[point] += 10;
+        }
+        assert_eq!(grid.values().filter(|v| **v >= 10).count(), 5);
+    }
+
+    #[test]
+    fn test_neighbours_wrapping_small() {
+        let empty = Grid::<u8>::from_fn(0, 0, |_| 0);
+        assert_eq!(empty.neighbours_wrapping((0, 0), &ADJACENT).count(), 0);
+
+        let single = Grid::from_fn(1, 1, |_| 0);
+        assert_eq!(
+            single
+                .neighbours_wrapping((0, 0), &ADJACENT)
+                .collect::<Vec<_>>(),
+            vec![(0, 0)]
+        );
+
+        let square = Grid::from_fn(2, 2, |_| 0);
+        assert_eq!(
+            square
+                .neighbours_wrapping((0, 0), &ORTHOGONAL)
+                .collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        // the eight offsets reach each of the other three cells, but never the point itself
+        assert_eq!(square.neighbours_wrapping((1, 1), &ADJACENT).count(), 3);
+    }
+
+    #[test]
+    fn test_from_rows() {
+        assert_eq!(
+            Grid::from_rows(vec![vec![0, 1, 2], vec![3, 4, 5]]),
+            Ok(grid())
+        );
+        assert_eq!(
+            Grid::from_rows(vec![vec![0, 1, 2], vec![3, 4]]),
+            Err(RaggedRow {
+                row: 1,
+                len: 2,
+                width: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = grid().map(|v| v % 2 == 0);
+
+        assert_eq!(grid.render(|b| if *b { '#' } else { '.' }), "#.#\n.#.\n");
+    }
+}
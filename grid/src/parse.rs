@@ -0,0 +1,89 @@
+use crate::Grid;
+use aoc_core::parse::{one_of, Error, Expected, IResult};
+use nom::{
+    character::complete::{line_ending, satisfy},
+    combinator::map,
+    multi::{many1, many_m_n},
+    Parser,
+};
+
+/// Parses lines of single digits such as `2199943210` into a grid of their values.
+pub fn digits(input: &str) -> IResult<&str, Grid<u8>> {
+    rows(map(satisfy(|c| c.is_ascii_digit()), |c| c as u8 - b'0')).parse(input)
+}
+
+/// Parses lines of `#` and `.` into a grid that is `true` where there's a `#`.
+pub fn pixels(input: &str) -> IResult<&str, Grid<bool>> {
+    rows(map(one_of("#."), |c| c == '#')).parse(input)
+}
+
+/// Parses lines of cells into a grid, failing on a line shorter than the first one.
+///
+/// The grid ends at the first line that doesn't start with a cell, such as a blank one, leaving its
+/// line ending unconsumed.
+pub fn rows<'a, T, P>(mut cell: P) -> impl Parser<&'a str, Output = Grid<T>, Error = Error<&'a str>>
+where
+    P: Parser<&'a str, Output = T, Error = Error<&'a str>>,
+{
+    move |input: &'a str| {
+        let (mut rest, mut cells) = many1(|i| cell.parse(i)).parse(input)?;
+        let width = cells.len();
+        let mut height = 1;
+
+        while let Ok((line, _)) = line_ending::<_, Error<&str>>(rest) {
+            let Ok((after, row)) = many_m_n(1, width, |i| cell.parse(i)).parse(line) else {
+                break;
+            };
+            if row.len() < width {
+                return Err(nom::Err::Failure(Error::new(
+                    after,
+                    Expected::Other(format!("a row of {width} cells")),
+                )));
+            }
+            cells.extend(row);
+            height += 1;
+            rest = after;
+        }
+
+        let grid = Grid {
+            cells,
+            width,
+            height,
+        };
+        Ok((rest, grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::parse::finish;
+
+    #[test]
+    fn test_digits() {
+        let grid = finish(0, "123\n456\n", digits("123\n456\n")).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(0, 1)], 4);
+    }
+
+    #[test]
+    fn test_pixels_sections() {
+        let (rest, grid) = pixels("#.\n.#\n\nfold").unwrap();
+
+        assert_eq!(grid.render(|p| if *p { '#' } else { '.' }), "#.\n.#\n");
+        assert_eq!(rest, "\n\nfold");
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let input = "123\n45\n";
+        let error = finish(0, input, digits(input)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "a row of 3 cells");
+
+        let input = "123\n4567\n";
+        let error = finish(0, input, digits(input)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+/// A cell of a [`SparseGrid`] or an [`InfiniteGrid`](crate::InfiniteGrid) as `(x, y)`, which may
+/// be negative.
+pub type Position = (isize, isize);
+
+/// A grid without bounds that only stores the positions that are set, such as the dots on a sheet
+/// of paper.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SparseGrid<T = ()> {
+    cells: HashMap<Position, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, position: Position, value: T) -> Option<T> {
+        self.cells.insert(position, value)
+    }
+
+    pub fn remove(&mut self, position: Position) -> Option<T> {
+        self.cells.remove(&position)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    /// The number of positions that are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The positions that are set, in no particular order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        self.cells.keys().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells
+            .iter()
+            .map(|(position, value)| (*position, value))
+    }
+
+    /// The smallest and largest coordinates that are set, `None` for an empty grid.
+    pub fn bounds(&self) -> Option<(Position, Position)> {
+        self.positions().fold(None, |bounds, (x, y)| match bounds {
+            None => Some(((x, y), (x, y))),
+            Some(((min_x, min_y), (max_x, max_y))) => {
+                Some(((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y))))
+            }
+        })
+    }
+
+    /// Moves every position, keeping one of the values where several end up at the same spot.
+    pub fn map_positions(self, mut f: impl FnMut(Position) -> Position) -> Self {
+        self.cells
+            .into_iter()
+            .map(|(position, value)| (f(position), value))
+            .collect()
+    }
+
+    /// Draws the bounds of the grid as lines of text, one character per position.
+    pub fn render(&self, mut cell: impl FnMut(Option<&T>) -> char) -> String {
+        let Some(((min_x, min_y), (max_x, max_y))) = self.bounds() else {
+            return String::new();
+        };
+
+        let mut output = String::new();
+        for y in min_y..=max_y {
+            output.extend((min_x..=max_x).map(|x| cell(self.get((x, y)))));
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+impl<T> FromIterator<(Position, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Position, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl FromIterator<Position> for SparseGrid {
+    fn from_iter<I: IntoIterator<Item = Position>>(iter: I) -> Self {
+        iter.into_iter().map(|position| (position, ())).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold() {
+        let grid = [(0, 0), (4, 1), (2, 2)].into_iter().collect::<SparseGrid>();
+
+        let folded = grid.map_positions(|(x, y)| (if x > 2 { 4 - x } else { x }, y));
+
+        assert_eq!(folded.len(), 3);
+        assert_eq!(folded.bounds(), Some(((0, 0), (2, 2))));
+        assert_eq!(
+            folded.render(|dot| if dot.is_some() { '#' } else { '.' }),
+            "#..\n#..\n..#\n"
+        );
+    }
+}